@@ -33,6 +33,7 @@ async fn main() -> std::io::Result<()> {
         let send_data = sctp_rs::SendData {
             payload: message.as_bytes().to_vec(),
             snd_info: None,
+            auth_key_id: None,
         };
         connected.sctp_send(send_data).await?;
         let received = connected.sctp_recv().await?;
@@ -41,6 +41,7 @@ async fn main() -> std::io::Result<()> {
             let send_data = sctp_rs::SendData {
                 payload: response.as_bytes().to_vec(),
                 snd_info: None,
+                auth_key_id: None,
             };
             accepted.sctp_send(send_data).await?;
         }
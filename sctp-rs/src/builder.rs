@@ -0,0 +1,260 @@
+//! Builder for an SCTP Socket.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use crate::{
+    AssociationChange, ConnectedSocket, Event, Socket, SocketToAssociation, SubscribeEventAssocId,
+};
+
+/// A Builder for the [`Socket`].
+///
+/// Setting up a socket often requires certain socket options to be set before the socket is
+/// used to `listen` or `connect`, for example, subscribing to the events, so that no events
+/// are missed for the very first association. The builder applies all these options when the
+/// [`Socket`] is built.
+///
+/// ```rust,no_run
+/// # fn build() -> std::io::Result<()> {
+/// use sctp_rs::{Event, SocketBuilder, SocketToAssociation};
+///
+/// let socket = SocketBuilder::new(SocketToAssociation::OneToMany)
+///     .subscribe(&[Event::Association, Event::Shutdown])
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SocketBuilder {
+    assoc: SocketToAssociation,
+    v6: bool,
+    events: Vec<Event>,
+    handshake: Option<HandshakeParams>,
+}
+
+impl SocketBuilder {
+    /// Create a new builder for an IPv4 family socket of the given [`SocketToAssociation`]
+    /// style.
+    pub fn new(assoc: SocketToAssociation) -> Self {
+        Self {
+            assoc,
+            v6: false,
+            events: vec![],
+            handshake: None,
+        }
+    }
+
+    /// Build an IPv6 family socket instead.
+    pub fn v6(mut self) -> Self {
+        self.v6 = true;
+        self
+    }
+
+    /// Subscribe to the given events for all the future associations of the socket.
+    pub fn subscribe(mut self, events: &[Event]) -> Self {
+        self.events.extend_from_slice(events);
+        self
+    }
+
+    /// Set the handshake parameters for all the future associations of the socket. See
+    /// [`HandshakeParams`].
+    pub fn handshake(mut self, params: HandshakeParams) -> Self {
+        self.handshake = Some(params);
+        self
+    }
+
+    /// Create the [`Socket`] and apply all the options to it.
+    pub fn build(self) -> std::io::Result<Socket> {
+        let socket = if self.v6 {
+            Socket::new_v6(self.assoc)?
+        } else {
+            Socket::new_v4(self.assoc)?
+        };
+
+        if !self.events.is_empty() {
+            socket.sctp_subscribe_events(&self.events, SubscribeEventAssocId::Future)?;
+        }
+
+        if let Some(ref params) = self.handshake {
+            socket.set_handshake_params(params)?;
+        }
+
+        Ok(socket)
+    }
+}
+
+/// Parameters of the association setup (the `INIT` and the `COOKIE` handshake).
+///
+/// These parameters are spread over the `SCTP_INITMSG` (See Section 8.1.3 of RFC 6458) and the
+/// `SCTP_ASSOCINFO` (See Section 8.1.2 of RFC 6458) socket options. Lowering the cookie life
+/// limits the time a (possibly spoofed) `INIT` can be replayed using its cookie, while the `INIT`
+/// retransmissions limit the time spent trying to set up an association with an unresponsive
+/// peer. Only the parameters that are set are changed, the rest keep their current values.
+///
+/// ```rust,no_run
+/// # fn build() -> std::io::Result<()> {
+/// use sctp_rs::{HandshakeParams, SocketBuilder, SocketToAssociation};
+///
+/// let socket = SocketBuilder::new(SocketToAssociation::OneToOne)
+///     .handshake(
+///         HandshakeParams::new()
+///             .cookie_life(10_000)
+///             .max_init_attempts(4)
+///             .max_init_timeout(5_000),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct HandshakeParams {
+    pub(crate) cookie_life: Option<u32>,
+    pub(crate) max_init_attempts: Option<u16>,
+    pub(crate) max_init_timeout: Option<u16>,
+}
+
+impl HandshakeParams {
+    /// Create new parameters, that do not change anything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Life time of the `COOKIE` (in milliseconds) sent to the peers.
+    pub fn cookie_life(mut self, ms: u32) -> Self {
+        self.cookie_life = Some(ms);
+        self
+    }
+
+    /// Maximum number of the `INIT` transmissions.
+    pub fn max_init_attempts(mut self, attempts: u16) -> Self {
+        self.max_init_attempts = Some(attempts);
+        self
+    }
+
+    /// Maximum RTO (in milliseconds) used while retransmitting the `INIT`.
+    pub fn max_init_timeout(mut self, ms: u16) -> Self {
+        self.max_init_timeout = Some(ms);
+        self
+    }
+}
+
+// Parameters of the `INIT` message, see `Socket::sctp_setup_init_params`.
+#[derive(Debug, Clone, Copy)]
+struct InitMsgParams {
+    ostreams: u16,
+    istreams: u16,
+    retries: u16,
+    timeout: u16,
+}
+
+/// A Builder for a client [`ConnectedSocket`].
+///
+/// Unlike [`Socket::connect`], that returns as soon as the handshake is started, the
+/// [`connect`][Self::connect] returns the [`ConnectedSocket`] only after the association is up,
+/// along with the [`AssociationChange`] Notification (`CommUp`) that carries the number of the
+/// streams negotiated with the peer.
+///
+/// ```rust,no_run
+/// # async fn connect() -> std::io::Result<()> {
+/// use sctp_rs::{ClientBuilder, Event};
+///
+/// let (connected, comm_up) = ClientBuilder::new()
+///     .init_msg(10, 10, 0, 0)
+///     .subscribe(&[Event::Shutdown])
+///     .wait_established(std::time::Duration::from_secs(5))
+///     .connect(&["127.0.0.1:8080".parse().unwrap()])
+///     .await?;
+/// println!("Outbound streams: {}", comm_up.ob_streams);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    v6: bool,
+    events: Vec<Event>,
+    init: Option<InitMsgParams>,
+    timeout: Option<Duration>,
+}
+
+impl ClientBuilder {
+    /// Create a new builder for an IPv4 family client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build an IPv6 family client instead.
+    pub fn v6(mut self) -> Self {
+        self.v6 = true;
+        self
+    }
+
+    /// Set the parameters of the `INIT` message. See
+    /// [`sctp_setup_init_params`][Socket::sctp_setup_init_params].
+    pub fn init_msg(mut self, ostreams: u16, istreams: u16, retries: u16, timeout: u16) -> Self {
+        self.init = Some(InitMsgParams {
+            ostreams,
+            istreams,
+            retries,
+            timeout,
+        });
+        self
+    }
+
+    /// Subscribe to the given events for the association.
+    ///
+    /// The [`Association`][Event::Association] event is always subscribed to while connecting,
+    /// it is unsubscribed from once the association is up, unless it is one of the `events`.
+    pub fn subscribe(mut self, events: &[Event]) -> Self {
+        self.events.extend_from_slice(events);
+        self
+    }
+
+    /// Wait at most `timeout` for the association to come up. By default, it is waited for until
+    /// the `INIT` retransmissions are exhausted.
+    pub fn wait_established(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Connect to the peer at the given `addrs` and wait until the association is up.
+    ///
+    /// If the association is not up within the timeout (See
+    /// [`wait_established`][Self::wait_established]), the socket is closed and an error of the kind
+    /// [`TimedOut`][std::io::ErrorKind::TimedOut] is returned. If the association cannot be
+    /// started, an error of the kind [`ConnectionRefused`][std::io::ErrorKind::ConnectionRefused]
+    /// is returned. Any other Notification received before the association is up is discarded.
+    pub async fn connect(
+        self,
+        addrs: &[SocketAddr],
+    ) -> std::io::Result<(ConnectedSocket, AssociationChange)> {
+        let socket = if self.v6 {
+            Socket::new_v6(SocketToAssociation::OneToOne)?
+        } else {
+            Socket::new_v4(SocketToAssociation::OneToOne)?
+        };
+
+        let mut events = self.events.clone();
+        if !events.contains(&Event::Association) {
+            events.push(Event::Association);
+        }
+        socket.sctp_subscribe_events(&events, SubscribeEventAssocId::Future)?;
+
+        if let Some(init) = self.init {
+            socket.sctp_setup_init_params(
+                init.ostreams,
+                init.istreams,
+                init.retries,
+                init.timeout,
+            )?;
+        }
+
+        let (connected, comm_up) = socket.sctp_connectx_comm_up(addrs, self.timeout).await?;
+
+        if !self.events.contains(&Event::Association) {
+            connected
+                .sctp_unsubscribe_events(&[Event::Association], SubscribeEventAssocId::Current)?;
+        }
+
+        Ok((connected, comm_up))
+    }
+}
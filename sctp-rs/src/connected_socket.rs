@@ -5,11 +5,16 @@ use tokio::io::unix::AsyncFd;
 use std::net::SocketAddr;
 use std::os::unix::io::RawFd;
 
+use crate::consts::{SCTP_ABORT, SCTP_EOF};
 #[allow(unused)]
 use crate::internal::*;
+use crate::split::split_internal;
+use crate::types::internal::{AuthKeys, Reassembly, ReceiveState, SendBlocked, SentSsns};
 use crate::{
-    AssociationId, BindxFlags, ConnStatus, Event, NotificationOrData, SendData, SendInfo,
-    SubscribeEventAssocId,
+    AssocInfo, AssociationId, AuthKeyState, BindxFlags, ConnState, ConnStatus, Event, Incoming,
+    NotificationOrData, NotificationQueue, PeerAddrParams, ReceivedMessageInfo, RtoInfo,
+    SctpReceiver, SctpSender, SendData, SendInfo, SubscribeEventAssocId,
+    UnsupportedNotificationPolicy,
 };
 
 /// A structure representing a Connected SCTP socket.
@@ -24,6 +29,10 @@ use crate::{
 #[derive(Debug)]
 pub struct ConnectedSocket {
     inner: AsyncFd<RawFd>,
+    auth_keys: AuthKeys,
+    state: ReceiveState,
+    sent_ssns: SentSsns,
+    send_blocked: SendBlocked,
 }
 
 impl ConnectedSocket {
@@ -37,9 +46,44 @@ impl ConnectedSocket {
     pub fn from_rawfd(rawfd: RawFd) -> std::io::Result<Self> {
         Ok(Self {
             inner: AsyncFd::new(rawfd)?,
+            auth_keys: AuthKeys::default(),
+            sent_ssns: SentSsns::default(),
+            send_blocked: SendBlocked::default(),
+            state: ReceiveState::default(),
         })
     }
 
+    /// Creates new [`ConnectedSocket`] from a [`RawFd`][std::os::unix::io::RawFd], after
+    /// validating that it is an SCTP socket.
+    ///
+    /// This guards against adopting a socket of a different protocol (eg. one passed by another
+    /// process over a Unix domain socket). If the `rawfd` is not an SCTP socket, an error of the
+    /// kind [`InvalidInput`][std::io::ErrorKind::InvalidInput] is returned and the `rawfd` is left
+    /// open, the caller still owns it.
+    pub fn from_raw_fd_checked(rawfd: RawFd) -> std::io::Result<Self> {
+        let inner = AsyncFd::new(rawfd)?;
+        if !is_sctp_internal(&inner) {
+            let _ = inner.into_inner();
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "Not an SCTP socket.",
+            ));
+        }
+
+        Ok(Self {
+            inner,
+            auth_keys: AuthKeys::default(),
+            sent_ssns: SentSsns::default(),
+            send_blocked: SendBlocked::default(),
+            state: ReceiveState::default(),
+        })
+    }
+
+    pub(crate) fn with_auth_keys(mut self, auth_keys: AuthKeys) -> Self {
+        self.auth_keys = auth_keys;
+        self
+    }
+
     /// Perform a TCP like half close.
     ///
     /// Note: however that the semantics for TCP and SCTP half close are different. See section
@@ -48,12 +92,18 @@ impl ConnectedSocket {
         shutdown_internal(&self.inner, how)
     }
 
-    /// Bind to addresses on the given socket. See Section 9.1 RFC 6458.
+    /// Add or remove the local addresses of the established association. See Section 9.1 RFC
+    /// 6458.
     ///
-    /// For the connected sockets, this feature is optional and hence will *always* return
-    /// `ENOTSUP(EOPNOTSUP)` error.
-    pub fn sctp_bindx(&self, _addrs: &[SocketAddr], _flags: BindxFlags) -> std::io::Result<()> {
-        Err(std::io::Error::from_raw_os_error(95))
+    /// The peer is informed of the added or removed addresses using the Dynamic Address
+    /// Reconfiguration (`ASCONF`, RFC 5061), the peer reports them using the
+    /// [`PeerAddrChange`][crate::PeerAddrChange] notification. If `ASCONF` was not negotiated with
+    /// the peer when the association was set up, an error wrapping
+    /// [`SctpError::AsconfNotSupported`][crate::SctpError::AsconfNotSupported] is returned. On
+    /// Linux, `ASCONF` is supported only when enabled using the `net.sctp.addip_enable` sysctl
+    /// (disabled by default) on both the endpoints.
+    pub fn sctp_bindx(&self, addrs: &[SocketAddr], flags: BindxFlags) -> std::io::Result<()> {
+        sctp_bindx_connected_internal(&self.inner, addrs, flags)
     }
 
     /// Get Peer addresses for the association. See Section 9.3 RFC 6458.
@@ -66,21 +116,260 @@ impl ConnectedSocket {
         sctp_getladdrs_internal(&self.inner, assoc_id)
     }
 
+    /// Get the local address of the socket.
+    ///
+    /// See [`Socket::local_addr`][crate::Socket::local_addr] for details.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        local_addr_internal(&self.inner)
+    }
+
+    /// Whether the socket is an SCTP socket, ie. its protocol (`SO_PROTOCOL`) is `IPPROTO_SCTP`.
+    ///
+    /// See [`Socket::is_sctp`][crate::Socket::is_sctp] for details.
+    pub fn is_sctp(&self) -> bool {
+        is_sctp_internal(&self.inner)
+    }
+
     /// Receive Data or Notification from the connected socket.
     ///
     /// The internal API used to receive the data is also the API used to receive notifications.
     /// This function returns either the notification (which the user should have subscribed for)
     /// or the data.
+    ///
+    /// A message that is received in multiple parts (eg. larger than the receive buffer) is
+    /// reassembled by default and only the complete message is returned (See
+    /// [`recv_coalesced`][Self::recv_coalesced]). A message larger than the maximum message size
+    /// (See [`set_max_message_size`][Self::set_max_message_size]) is discarded and an error
+    /// wrapping [`SctpError::MessageTooLarge`][crate::SctpError::MessageTooLarge] is returned. The
+    /// reassembly can be turned off using [`set_reassembly`][Self::set_reassembly].
+    ///
+    /// All the APIs receiving on this socket ([`try_recv`][Self::try_recv],
+    /// [`incoming`][Self::incoming] and [`notification_queue`][Self::notification_queue]) receive
+    /// the messages the same way, except [`recv_into`][Self::recv_into], which never reassembles.
     pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
-        sctp_recvmsg_internal(&self.inner).await
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Configured).await
+    }
+
+    /// Turn the reassembly of the messages received on this socket on or off. The reassembly is
+    /// on by default. See [`sctp_recv`][Self::sctp_recv] for the APIs that reassemble.
+    ///
+    /// With the reassembly off, each part of a message received in multiple parts is returned as
+    /// is, and the caller cannot tell whether the message is complete (use
+    /// [`recv_into`][Self::recv_into] which reports the end of the message instead). Turning the
+    /// reassembly off does not discard the parts already buffered.
+    pub fn set_reassembly(&self, on: bool) {
+        self.state.partial.set_enabled(on)
+    }
+
+    /// Set the maximum size of a message reassembled on this socket (See
+    /// [`sctp_recv`][Self::sctp_recv]). The default is 1 MiB.
+    pub fn set_max_message_size(&self, max: usize) {
+        self.state.partial.set_max_message_size(max)
+    }
+
+    /// Receive Data or Notification into the given buffer.
+    ///
+    /// Unlike [`sctp_recv`][Self::sctp_recv], which allocates a new buffer for every message,
+    /// the buffer is cleared and filled in place, it is grown only if its capacity is smaller than
+    /// the default receive buffer size. This allows reusing the same buffer for receiving all the
+    /// messages. The returned [`ReceivedMessageInfo`] tells whether a Notification or Data is
+    /// received, along with the ancillary data if any.
+    ///
+    /// The messages are never reassembled (regardless of [`set_reassembly`][Self::set_reassembly]),
+    /// the [`eor`][ReceivedMessageInfo::eor] tells the last part of a message instead. This should
+    /// not be mixed with the other receive APIs while the reassembly is on, since the parts of a
+    /// message buffered by those are not returned here.
+    pub async fn recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedMessageInfo> {
+        sctp_recvmsg_into_resubscribe_internal(&self.inner, &self.state, buf).await
+    }
+
+    /// Receive Data or Notification from the socket without waiting.
+    ///
+    /// Returns `Ok(None)` if there is nothing to receive right now (ie. `recvmsg` fails with
+    /// `EAGAIN` / `EWOULDBLOCK`), this is useful when the socket is polled by an event loop outside
+    /// of `tokio`. The sockets are always in the non-blocking mode, so this never blocks. Mixing
+    /// [`sctp_recv`][Self::sctp_recv] and `try_recv` on the same socket is the caller's
+    /// responsibility, a message is received by only one of them. The messages are reassembled as
+    /// by [`sctp_recv`][Self::sctp_recv].
+    pub fn try_recv(&self) -> std::io::Result<Option<NotificationOrData>> {
+        sctp_try_recvmsg_reassembled_internal(&self.inner, &self.state)
+    }
+
+    /// Set the non-blocking mode of the socket.
+    ///
+    /// The sockets are always created in the non-blocking mode, which is required by all the
+    /// `async` APIs: the socket is registered with the `tokio` runtime, where a blocking call
+    /// would block the runtime thread. Hence turning the non-blocking mode off (`on` set to
+    /// `false`) is rejected with an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+    pub fn set_nonblocking(&self, on: bool) -> std::io::Result<()> {
+        set_nonblocking_internal(&self.inner, on)
+    }
+
+    /// Receive a Notification or a complete Data message of at most `max` bytes.
+    ///
+    /// A message larger than the receive buffer is received in multiple parts, which are
+    /// reassembled here until the end of the message (`MSG_EOR`), even if the reassembly is turned
+    /// off for the socket. If the message is larger than `max` bytes, it is discarded and an error
+    /// wrapping [`SctpError::MessageTooLarge`][crate::SctpError::MessageTooLarge] is returned,
+    /// this protects against the peer sending unbounded messages. The Notifications received
+    /// while waiting for the rest of a message are returned right away, the parts received so far
+    /// are kept for the next call.
+    pub async fn recv_message_bounded(&self, max: usize) -> std::io::Result<NotificationOrData> {
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Bounded(max)).await
+    }
+
+    /// Receive a Notification or a complete Data message, even if the reassembly is turned off
+    /// (See [`set_reassembly`][Self::set_reassembly]).
+    ///
+    /// The parts of the messages that are received in multiple parts are coalesced (See
+    /// [`MessageReassembler`][crate::MessageReassembler]), per association and stream, and only
+    /// the complete messages are returned. The Notifications are returned as they are received.
+    /// The buffered parts of a message are discarded when its partial delivery is aborted (See
+    /// [`PdapiEvent`][crate::PdapiEvent]), or using [`reset_reassembly`][Self::reset_reassembly].
+    pub async fn recv_coalesced(&self) -> std::io::Result<NotificationOrData> {
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Always).await
+    }
+
+    /// Discard the parts of the message buffered for the reassembly (See
+    /// [`sctp_recv`][Self::sctp_recv]) on the given association and stream.
+    ///
+    /// This is required only when the application abandons a message, the parts of a message
+    /// whose partial delivery is aborted are discarded when the Notification is received.
+    pub fn reset_reassembly(&self, assoc_id: AssociationId, stream: u16) {
+        self.state.partial.reset(assoc_id, stream)
+    }
+
+    /// Returns an [`Incoming`] adaptor to receive on this socket until the association is closed.
+    ///
+    /// See [`Incoming::next`] for details.
+    pub fn incoming(&self) -> Incoming<'_> {
+        Incoming::new(&self.inner, &self.state)
+    }
+
+    /// Returns a [`NotificationQueue`] adaptor to receive the Notifications and the Data
+    /// separately on this socket.
+    pub fn notification_queue(&self) -> NotificationQueue<'_> {
+        NotificationQueue::new(&self.inner, &self.state)
+    }
+
+    /// Split the socket into owned send and receive halves.
+    ///
+    /// This allows one task to send while another receives on the same association, without
+    /// wrapping the socket in a `Mutex`. The halves share the underlying socket, which is closed
+    /// exactly once, when both the halves are dropped.
+    pub fn split(self) -> (SctpSender, SctpReceiver) {
+        split_internal(self)
     }
 
     /// Send Data and Anciliary data if any on the SCTP Socket.
     ///
     /// SCTP supports sending the actual SCTP message together with sending any anciliary data on
     /// the SCTP association. The anciliary data is optional.
+    ///
+    /// SCTP cannot carry zero length messages, an empty `payload` returns an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput]. For application keepalives use
+    /// [`set_heartbeat_interval`][Self::set_heartbeat_interval] instead.
+    ///
+    /// When the send buffer of the socket is full, an error of the kind
+    /// [`WouldBlock`][std::io::ErrorKind::WouldBlock] is returned, see also
+    /// [`last_send_blocked`][Self::last_send_blocked].
     pub async fn sctp_send(&self, data: SendData) -> std::io::Result<()> {
-        sctp_sendmsg_internal(&self.inner, None, data).await
+        sctp_send_blocked_internal(&self.inner, &self.send_blocked, data).await
+    }
+
+    /// Whether the most recent [`sctp_send`][Self::sctp_send] was blocked by the flow control.
+    ///
+    /// This is `true` if the last send failed with an error of the kind
+    /// [`WouldBlock`][std::io::ErrorKind::WouldBlock], because the send buffer was full.
+    pub fn last_send_blocked(&self) -> bool {
+        self.send_blocked.get()
+    }
+
+    /// Send an ordered message and return the Stream Sequence Number (SSN) assigned to it.
+    ///
+    /// This allows correlating the sent messages with the later
+    /// [`SendFailed`][crate::Notification::SendFailed] events. The kernel does not report back the
+    /// SSN, hence the messages sent using this API are counted per association and stream, the
+    /// first message on a stream getting the SSN `0`. The count is only accurate if all the
+    /// ordered messages on the stream are sent using this API, one at a time. An unordered message
+    /// returns an error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput]. When
+    /// `snd_info` is `None`, the message is counted on the stream of the default send parameters
+    /// (See [`set_default_sndinfo`][Self::set_default_sndinfo]).
+    pub async fn send_tracked(&self, data: SendData) -> std::io::Result<u16> {
+        sctp_send_tracked_internal(&self.inner, &self.sent_ssns, data).await
+    }
+
+    /// Send each of the `payloads` as a separate message, distributing them over the `streams`
+    /// in turn.
+    ///
+    /// The first payload is sent on the first stream, the second payload on the second stream and
+    /// so on, starting again from the first stream after the last one. If sending any of the
+    /// messages fails, the error is returned right away and the remaining payloads are not sent.
+    /// An empty `streams` returns an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+    pub async fn send_round_robin(
+        &self,
+        payloads: &[Vec<u8>],
+        streams: &[u16],
+    ) -> std::io::Result<()> {
+        sctp_send_round_robin_internal(&self.inner, payloads, streams).await
+    }
+
+    /// Send the `payload` on the stream `sid`, split into the messages that fit in a single packet
+    /// each.
+    ///
+    /// The `payload` is split into the messages no larger than the current
+    /// [`fragmentation_point`][Self::fragmentation_point] of the association, each sent as a
+    /// separate complete message, so the peer receives multiple messages, not the `payload` as a
+    /// whole. Returns the number of the messages sent. If sending any of the messages fails, the
+    /// error is returned right away and the rest of the `payload` is not sent.
+    pub async fn send_fitting(&self, payload: &[u8], sid: u16) -> std::io::Result<usize> {
+        sctp_send_fitting_internal(&self.inner, payload, sid).await
+    }
+
+    /// Send Data and wait until the peer acknowledges all the outstanding data on the association.
+    ///
+    /// This is a lightweight delivery confirmation for low rate critical messages. After sending
+    /// `data`, the `SENDER_DRY` event (See Section 6.1.9 of RFC 6458) is awaited for at the most
+    /// `timeout`. Returns `true` if the event was received, ie. all the data sent so far including
+    /// this message is acknowledged by the peer and `false` if the `timeout` elapsed.
+    ///
+    /// Note: Any data or notifications received on the socket while waiting for the event are
+    /// held and returned by the next receive calls on the socket, in the order they were received.
+    /// The subscription to the [`SenderDry`][crate::Event::SenderDry] event is left as it was
+    /// before the call. A stale event from an earlier send is not taken as the confirmation, each
+    /// event is checked against the [`status`][Self::sctp_get_status] of the association.
+    pub async fn send_confirmed(
+        &self,
+        data: SendData,
+        timeout: std::time::Duration,
+    ) -> std::io::Result<bool> {
+        sctp_send_confirmed_internal(&self.inner, &self.state, data, timeout).await
+    }
+
+    /// Abort the given association with a user initiated abort reason.
+    ///
+    /// The association is torn down immediately by sending an ABORT chunk carrying `reason` as
+    /// the 'User Initiated Abort' cause (`SCTP_ABORT` flag, See Section 5.3.4 of RFC 6458). Any
+    /// data not yet delivered is discarded. The peer sees an
+    /// [`AssociationChange`][crate::AssociationChange] notification with the state
+    /// [`CommLost`][crate::AssocChangeState::CommLost].
+    pub async fn abort(&self, assoc_id: AssociationId, reason: &[u8]) -> std::io::Result<()> {
+        sctp_send_flags_internal(&self.inner, assoc_id, SCTP_ABORT, reason).await
+    }
+
+    /// Gracefully shutdown the given association.
+    ///
+    /// A graceful shutdown of the association is initiated (`SCTP_EOF` flag, See Section 5.3.4 of
+    /// RFC 6458), any data that is already queued is delivered before the association is closed.
+    /// The peer sees a [`Shutdown`][crate::Shutdown] notification followed by an
+    /// [`AssociationChange`][crate::AssociationChange] notification with the state
+    /// [`ShutdownComplete`][crate::AssocChangeState::ShutdownComplete].
+    /// This is mostly useful for the One to Many sockets, where the socket itself cannot be
+    /// `shutdown` without affecting other associations.
+    pub async fn shutdown_assoc(&self, assoc_id: AssociationId) -> std::io::Result<()> {
+        sctp_send_flags_internal(&self.inner, assoc_id, SCTP_EOF, &[]).await
     }
 
     /// Subscribe to a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
@@ -109,6 +398,31 @@ impl ConnectedSocket {
         sctp_subscribe_event_internal(&self.inner, event, assoc_id, false)
     }
 
+    /// Subscribe to the `events` again, whenever an association of this socket is restarted.
+    ///
+    /// The subscriptions of an association may be reset when the association is restarted (ie. an
+    /// [`AssociationChange`][crate::AssociationChange] notification with the state
+    /// [`Restart`][crate::AssocChangeState::Restart] is received), which silently stops the
+    /// events. With this set, all the APIs receiving on this socket (including the
+    /// [`incoming`][Self::incoming] and the [`notification_queue`][Self::notification_queue]
+    /// adaptors) subscribe to the `events` for the restarted association, when they receive the
+    /// `Restart` notification. The [`Association`][crate::Event::Association]
+    /// event itself has to be subscribed to for the restart to be observed. An empty `events`
+    /// turns this off.
+    pub fn set_resubscribe_on_restart(&self, events: &[Event]) {
+        self.state.resubscribe.set(events);
+    }
+
+    /// Set what [`sctp_recv`][Self::sctp_recv] and [`try_recv`][Self::try_recv] do when a
+    /// Notification not supported by this crate is received.
+    ///
+    /// By default the [`Unsupported`][crate::Notification::Unsupported] Notification is returned,
+    /// with [`Skip`][UnsupportedNotificationPolicy::Skip] it is skipped and the next Notification
+    /// or Data is received instead. See [`UnsupportedNotificationPolicy`].
+    pub fn set_unsupported_notification_policy(&self, policy: UnsupportedNotificationPolicy) {
+        self.state.policy.set(policy);
+    }
+
     /// Subscribe to SCTP Events. See section 6.2.1 of RFC6458.
     ///
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
@@ -130,10 +444,7 @@ impl ConnectedSocket {
         if failures.is_empty() {
             Ok(())
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
+            Err(std::io::Error::other(format!("{:?}", failures)))
         }
     }
 
@@ -156,13 +467,36 @@ impl ConnectedSocket {
         if failures.is_empty() {
             Ok(())
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
+            Err(std::io::Error::other(format!("{:?}", failures)))
         }
     }
 
+    /// Subscribe to all the SCTP Events that are delivered as notifications.
+    ///
+    /// This is a convenience API mostly useful while debugging, where it is desirable to see the
+    /// full notification stream. All [`Event`]s except [`Event::DataIo`] are subscribed to and
+    /// the first subscription error (if any) is returned.
+    pub fn enable_all_events(&self, assoc_id: SubscribeEventAssocId) -> std::io::Result<()> {
+        sctp_enable_all_events_internal(&self.inner, assoc_id)
+    }
+
+    /// Setup parameters for a new association.
+    ///
+    /// This is useful only when the [`ConnectedSocket`] is a One to Many socket, where new
+    /// associations can be set up after this call. For a connected One to One socket, the
+    /// parameters would have no effect and an
+    /// [`InitMsgAfterConnect`][crate::SctpError::InitMsgAfterConnect] error is returned. See
+    /// [`Socket::sctp_setup_init_params`][crate::Socket::sctp_setup_init_params].
+    pub fn sctp_setup_init_params(
+        &self,
+        ostreams: u16,
+        istreams: u16,
+        retries: u16,
+        timeout: u16,
+    ) -> std::io::Result<()> {
+        sctp_setup_init_params_internal(&self.inner, ostreams, istreams, retries, timeout)
+    }
+
     /// Request to receive `RcvInfo` ancillary data.
     ///
     /// SCTP allows receiving ancillary data about the curent data received on the given socket.
@@ -184,6 +518,34 @@ impl ConnectedSocket {
         sctp_get_status_internal(&self.inner, assoc_id)
     }
 
+    /// Get the state of the association, eg. to follow the progress of a graceful shutdown.
+    ///
+    /// After a [`shutdown`][Self::shutdown], the association goes through the `ShutdownSent`
+    /// (and `ShutdownPending` if there is unacknowledged data) states, or the `ShutdownReceived`
+    /// and `ShutdownAckSent` states if the peer initiated the shutdown. The association is closed
+    /// once the peer completes the shutdown as well, [`ConnState::Closed`] is returned then.
+    pub fn shutdown_state(&self, assoc_id: AssociationId) -> std::io::Result<ConnState> {
+        sctp_get_conn_state_internal(&self.inner, assoc_id, true)
+    }
+
+    /// Get the Path MTU of the given association, ie. the smallest Path MTU of all the paths
+    /// to the peer.
+    ///
+    /// The data may be sent on any of the paths (eg. after a failover), so this is the MTU that
+    /// is relevant for sizing the messages, rather than the MTU of the primary path alone.
+    pub fn association_pmtu(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_association_pmtu_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the fragmentation point of the given association.
+    ///
+    /// This is the largest message (in bytes) that is sent without being fragmented into
+    /// multiple `DATA` chunks (See also [`ConnStatus`]). Sizing application messages to this
+    /// value allows them to be delivered in a single packet.
+    pub fn fragmentation_point(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        Ok(sctp_get_status_internal(&self.inner, assoc_id)?.fragmentation_pt)
+    }
+
     /// Set Default `SendInfo` values for this socket.
     ///
     /// In the [`sctp_send`] API, an optional `SendInfo` is present, which can be used to specify the
@@ -193,6 +555,363 @@ impl ConnectedSocket {
     pub fn sctp_set_default_sendinfo(&self, sendinfo: SendInfo) -> std::io::Result<()> {
         sctp_set_default_sendinfo_internal(&self.inner, sendinfo)
     }
+
+    /// Set Default `SendInfo` values for the given association.
+    ///
+    /// Like [`sctp_set_default_sendinfo`][`Self::sctp_set_default_sendinfo`], but the defaults
+    /// are applied to the association identified by `assoc_id`. Subsequent calls to
+    /// [`sctp_send`][`Self::sctp_send`] without any `snd_info` will use these defaults (stream
+    /// ID, PPID and context).
+    pub fn set_default_sndinfo(
+        &self,
+        info: SendInfo,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<()> {
+        sctp_set_default_sendinfo_internal(&self.inner, SendInfo { assoc_id, ..info })
+    }
+
+    /// Get the Default `SendInfo` values for the given association.
+    pub fn get_default_sndinfo(&self, assoc_id: AssociationId) -> std::io::Result<SendInfo> {
+        sctp_get_default_sendinfo_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the Peer Address Parameters for the given association. See Section 8.1.12 of RFC 6458.
+    ///
+    /// When `address` is `None`, the parameters for the association (or the socket defaults) are
+    /// returned, otherwise the parameters for the given path (Peer Address) are returned.
+    pub fn peer_addr_params(
+        &self,
+        assoc_id: AssociationId,
+        address: Option<SocketAddr>,
+    ) -> std::io::Result<PeerAddrParams> {
+        sctp_get_peer_addr_params_internal(&self.inner, assoc_id, address)
+    }
+
+    /// Set the Peer Address Parameters. See Section 8.1.12 of RFC 6458.
+    ///
+    /// The Retransmission Timeout (RTO) cannot be set per path, see [`PeerAddrParams`].
+    pub fn set_peer_addr_params(&self, params: PeerAddrParams) -> std::io::Result<()> {
+        sctp_set_peer_addr_params_internal(&self.inner, params)
+    }
+
+    /// Set the Heartbeat Interval for all the current paths of the given association.
+    ///
+    /// Heartbeats are enabled and `interval` (with a miliseconds granularity) is set on each of
+    /// the peer addresses returned by [`sctp_getpaddrs`][`Self::sctp_getpaddrs`]. Paths added to
+    /// the association later are not affected.
+    pub fn set_heartbeat_interval(
+        &self,
+        assoc_id: AssociationId,
+        interval: std::time::Duration,
+    ) -> std::io::Result<()> {
+        sctp_set_heartbeat_interval_internal(&self.inner, assoc_id, interval)
+    }
+
+    /// Request an on demand heartbeat on the path to `addr` of the given association. See Section
+    /// 8.1.12 of RFC 6458.
+    ///
+    /// The heartbeat is sent right away, irrespective of the Heartbeat Interval. The result of the
+    /// probe is reported by the [`PeerAddrChange`][crate::PeerAddrChange] notifications, when the
+    /// state of the path changes.
+    pub fn request_heartbeat(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<()> {
+        sctp_request_heartbeat_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Measure the round trip time to the peer of the given association.
+    ///
+    /// An on demand heartbeat is sent on the primary path and the time until it is acknowledged
+    /// is returned. This is a liveness and latency probe, that does not involve the application on
+    /// the peer. If no acknowledgement is received within the `timeout`, an error of the kind
+    /// [`TimedOut`][std::io::ErrorKind::TimedOut] is returned.
+    ///
+    /// Note: The kernel does not report the acknowledgement of a heartbeat, it is observed as any
+    /// control chunk received on the association (See `SCTP_GET_ASSOC_STATS`). Hence this works
+    /// only on an idle association: an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput] is returned if the association has data
+    /// that is not sent or not acknowledged yet, and the measurement is off if the peer sends a
+    /// control chunk (like its own heartbeat) meanwhile. The statistics are polled from every
+    /// millisecond to every 16 milliseconds as the wait gets longer, which is the granularity of
+    /// the measurement. Reading the statistics resets the maximum observed RTO (`sas_maxrto`) of
+    /// the association.
+    pub async fn ping(
+        &self,
+        assoc_id: AssociationId,
+        timeout: std::time::Duration,
+    ) -> std::io::Result<std::time::Duration> {
+        sctp_ping_internal(&self.inner, assoc_id, timeout).await
+    }
+
+    /// Request an on demand heartbeat on all the current paths of the given association.
+    ///
+    /// This allows detecting the dead paths (or a half open association) proactively, before
+    /// sending the data. See [`request_heartbeat`][Self::request_heartbeat] for details.
+    pub fn probe_all_paths(&self, assoc_id: AssociationId) -> std::io::Result<()> {
+        sctp_probe_all_paths_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Differentiated Services Code Point (DSCP) of the packets sent on the path to `addr`
+    /// of the given association. See Section 8.1.12 of RFC 6458.
+    ///
+    /// An unspecified `addr` (eg. `0.0.0.0:0`) sets the DSCP for all the paths of the
+    /// association. `dscp` is the 6 bit code point (eg. `46` for Expedited Forwarding) and not the
+    /// Traffic Class / TOS byte, it is shifted into the upper 6 bits of that byte internally. Hence
+    /// the `dscp` of the [`PeerAddrParams`] reads back as `dscp << 2`.
+    pub fn set_dscp(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        dscp: u8,
+    ) -> std::io::Result<()> {
+        sctp_set_dscp_internal(&self.inner, assoc_id, addr, dscp)
+    }
+
+    /// Set the IPv6 Flow Label (20 bits) of the packets sent on the path to `addr` of the given
+    /// association. See Section 8.1.12 of RFC 6458.
+    ///
+    /// An unspecified `addr` (eg. `[::]:0`) sets the Flow Label for all the paths of the
+    /// association.
+    pub fn set_ipv6_flowlabel(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        flowlabel: u32,
+    ) -> std::io::Result<()> {
+        sctp_set_ipv6_flowlabel_internal(&self.inner, assoc_id, addr, flowlabel)
+    }
+
+    /// Set the primary destination address of the peer for the given association. See Section
+    /// 8.1.9 of RFC 6458.
+    ///
+    /// `addr` must be one of the peer addresses of the association (See
+    /// [`sctp_getpaddrs`][`Self::sctp_getpaddrs`]), otherwise an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput] is returned. When subscribed to
+    /// [`Event::Address`], a [`PeerAddrChange`][crate::PeerAddrChange] notification with the state
+    /// [`MadePrim`][crate::PeerAddrState::MadePrim] is received once the primary is changed.
+    pub fn set_primary_addr(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<()> {
+        sctp_set_primary_addr_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Get the Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` returns the socket wide defaults for the future associations.
+    pub fn get_rto_info(&self, assoc_id: AssociationId) -> std::io::Result<RtoInfo> {
+        sctp_get_rto_info_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` sets the socket wide defaults for the future associations. The
+    /// values are validated by the kernel, invalid values (eg. `min` larger than `max`) return an
+    /// error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+    pub fn set_rto_info(&self, rto_info: RtoInfo) -> std::io::Result<()> {
+        sctp_set_rto_info_internal(&self.inner, rto_info)
+    }
+
+    /// Get the Association parameters. See Section 8.1.2 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` returns the socket wide defaults for the future associations.
+    pub fn get_assoc_info(&self, assoc_id: AssociationId) -> std::io::Result<AssocInfo> {
+        sctp_get_assoc_info_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Association parameters. See Section 8.1.2 of RFC 6458.
+    ///
+    /// Only `max_rxt` and `cookie_life` can be set, the read-only values of the [`AssocInfo`]
+    /// (`peer_destinations`, `peer_rwnd` and `local_rwnd`) are ignored and are not changed.
+    pub fn set_assoc_info(&self, assoc_info: AssocInfo) -> std::io::Result<()> {
+        sctp_set_assoc_info_internal(&self.inner, assoc_info)
+    }
+
+    /// Get the maximum size of the `DATA` chunks (fragments) of the user messages. See Section
+    /// 8.1.16 of RFC 6458.
+    ///
+    /// A value of `0` means the fragment size is determined by the Path MTU.
+    pub fn maxseg(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_maxseg_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum size of the `DATA` chunks (fragments) of the user messages. See Section
+    /// 8.1.16 of RFC 6458.
+    ///
+    /// A `maxseg` of `0` lets the fragment size be determined by the Path MTU. The kernel never
+    /// uses a size larger than allowed by the Path MTU, the effective value can be read back from
+    /// the `fragmentation_pt` of the [`ConnStatus`][crate::ConnStatus] of an association.
+    pub fn set_maxseg(&self, assoc_id: AssociationId, maxseg: u32) -> std::io::Result<()> {
+        sctp_set_maxseg_internal(&self.inner, assoc_id, maxseg)
+    }
+
+    /// Get whether the fragmentation of the user messages is disabled. See Section 8.1.11 of RFC
+    /// 6458.
+    pub fn disable_fragments(&self) -> std::io::Result<bool> {
+        sctp_get_disable_fragments_internal(&self.inner)
+    }
+
+    /// Disable (or enable) the fragmentation of the user messages. See Section 8.1.11 of RFC 6458.
+    ///
+    /// When the fragmentation is disabled, a message is either sent in a single `DATA` chunk or
+    /// the send fails with the `EMSGSIZE` OS error, if the message is larger than the
+    /// fragmentation point (the `fragmentation_pt` of the [`ConnStatus`][crate::ConnStatus]). The
+    /// fragmentation point is determined by the Path MTU or by the
+    /// [`set_maxseg`][Self::set_maxseg] value if smaller.
+    pub fn set_disable_fragments(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_disable_fragments_internal(&self.inner, on)
+    }
+
+    /// Get whether the Nagle like algorithm is disabled (`SCTP_NODELAY`). See Section 8.1.5 of
+    /// RFC 6458.
+    pub fn nodelay(&self) -> std::io::Result<bool> {
+        sctp_get_nodelay_internal(&self.inner)
+    }
+
+    /// Disable (or enable) the Nagle like algorithm (`SCTP_NODELAY`).
+    ///
+    /// See [`Socket::set_nodelay`][crate::Socket::set_nodelay] for details.
+    pub fn set_nodelay(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_nodelay_internal(&self.inner, on)
+    }
+
+    /// Get the default PR-SCTP (RFC 3758) time to live of the messages sent on the association.
+    ///
+    /// See [`Socket::default_pr_ttl`][crate::Socket::default_pr_ttl] for details.
+    pub fn default_pr_ttl(
+        &self,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<Option<std::time::Duration>> {
+        sctp_get_default_pr_ttl_internal(&self.inner, assoc_id)
+    }
+
+    /// Configure the socket for a reliable, but bounded latency delivery.
+    ///
+    /// See [`Socket::configure_bounded_latency`][crate::Socket::configure_bounded_latency] for
+    /// details.
+    pub fn configure_bounded_latency(
+        &self,
+        max_latency: std::time::Duration,
+    ) -> std::io::Result<()> {
+        sctp_configure_bounded_latency_internal(&self.inner, max_latency)
+    }
+
+    /// Get whether User Message Interleaving (RFC 8260) is actually negotiated on the association.
+    ///
+    /// Interleaving is used only when enabled on both the ends (See
+    /// [`Socket::set_interleaving`][crate::Socket::set_interleaving]), this allows verifying that
+    /// the peer agreed to it.
+    pub fn interleaving_active(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_interleaving_active_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// Note: On Linux, the kernel doubles the size set using
+    /// [`set_recv_buffer_size`][Self::set_recv_buffer_size] (to account for the bookkeeping
+    /// overhead), and the doubled size is returned here.
+    pub fn recv_buffer_size(&self) -> std::io::Result<usize> {
+        get_recv_buffer_size_internal(&self.inner)
+    }
+
+    /// Set the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// The size is capped by the kernel to `net.core.rmem_max`. See
+    /// [`recv_buffer_size`][Self::recv_buffer_size] for the Linux quirk when reading back the size.
+    pub fn set_recv_buffer_size(&self, size: usize) -> std::io::Result<()> {
+        set_recv_buffer_size_internal(&self.inner, size)
+    }
+
+    /// Get the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// Note: On Linux, the kernel doubles the size set using
+    /// [`set_send_buffer_size`][Self::set_send_buffer_size] (to account for the bookkeeping
+    /// overhead), and the doubled size is returned here.
+    pub fn send_buffer_size(&self) -> std::io::Result<usize> {
+        get_send_buffer_size_internal(&self.inner)
+    }
+
+    /// Set the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// The size is capped by the kernel to `net.core.wmem_max`. See
+    /// [`send_buffer_size`][Self::send_buffer_size] for the Linux quirk when reading back the size.
+    pub fn set_send_buffer_size(&self, size: usize) -> std::io::Result<()> {
+        set_send_buffer_size_internal(&self.inner, size)
+    }
+
+    /// Set a shared key used for authenticating chunks. See Section 8.3.2 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` sets the key for the future associations on the socket.
+    pub fn set_shared_key(
+        &self,
+        assoc_id: AssociationId,
+        key_id: u16,
+        key: &[u8],
+    ) -> std::io::Result<()> {
+        sctp_set_shared_key_internal(&self.inner, assoc_id, key_id, key)?;
+        self.auth_keys.insert(assoc_id, key_id);
+        Ok(())
+    }
+
+    /// Make the shared key with `key_id` the active key for sending. See Section 8.3.3 of RFC
+    /// 6458.
+    ///
+    /// A different key may be used for a particular message using the
+    /// [`auth_key_id`][crate::SendData::auth_key_id] of the [`SendData`][crate::SendData].
+    pub fn activate_key(&self, assoc_id: AssociationId, key_id: u16) -> std::io::Result<()> {
+        sctp_activate_key_internal(&self.inner, assoc_id, key_id)
+    }
+
+    /// Get the active and the installed shared keys for the association. See Section 8.3 of RFC
+    /// 6458.
+    ///
+    /// The kernel does not report the installed keys, see [`AuthKeyState::installed`] for how
+    /// these are determined.
+    pub fn auth_key_state(&self, assoc_id: AssociationId) -> std::io::Result<AuthKeyState> {
+        sctp_auth_key_state_internal(&self.inner, &self.auth_keys, assoc_id)
+    }
+
+    /// Request that the chunks of the `chunk_type` are received authenticated. See Section 8.3.1
+    /// of RFC 6458.
+    ///
+    /// This applies only to the associations set up after the call.
+    pub fn set_auth_chunk(&self, chunk_type: u8) -> std::io::Result<()> {
+        sctp_set_auth_chunk_internal(&self.inner, chunk_type)
+    }
+
+    /// Set the remote UDP encapsulation port for SCTP over UDP. See RFC 6951.
+    ///
+    /// A `port` of `0` disables the encapsulation. An unspecified `addr` (eg. `0.0.0.0:0`) applies
+    /// the port to all the paths of the association, with an `assoc_id` of `0` this sets the
+    /// default for the future associations (this can be done before `connect`). Otherwise the
+    /// port is set only for the path to `addr`.
+    #[cfg(target_os = "linux")]
+    pub fn set_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        port: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_udp_encaps_port_internal(&self.inner, assoc_id, addr, port)
+    }
+
+    /// Get the priority (`SO_PRIORITY`) of the socket.
+    #[cfg(target_os = "linux")]
+    pub fn priority(&self) -> std::io::Result<u32> {
+        get_priority_internal(&self.inner)
+    }
+
+    /// Set the priority (`SO_PRIORITY`) of the socket.
+    ///
+    /// The priority is used by the Linux queueing disciplines for scheduling the egress packets.
+    /// Setting a priority outside of `0` to `6` requires the `CAP_NET_ADMIN` capability.
+    #[cfg(target_os = "linux")]
+    pub fn set_priority(&self, prio: u32) -> std::io::Result<()> {
+        set_priority_internal(&self.inner, prio)
+    }
 }
 
 impl Drop for ConnectedSocket {
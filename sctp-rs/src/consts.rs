@@ -10,6 +10,9 @@ pub(crate) static SCTP_SOCKOPT_BINDX_REM: libc::c_int = 101;
 // peel off a one to many socket
 pub(crate) static SCTP_SOCKOPT_PEELOFF: libc::c_int = 102;
 
+// get the list of association IDs on a one to many socket
+pub(crate) const SCTP_GET_ASSOC_ID_LIST: libc::c_int = 29;
+
 // get peer/localaddrs
 pub(crate) static SCTP_GET_PEER_ADDRS: libc::c_int = 108;
 pub(crate) static SCTP_GET_LOCAL_ADDRS: libc::c_int = 109;
@@ -18,13 +21,27 @@ pub(crate) static SCTP_GET_LOCAL_ADDRS: libc::c_int = 109;
 pub(crate) static SCTP_SOCKOPT_CONNECTX: libc::c_int = 110;
 pub(crate) static SCTP_SOCKOPT_CONNECTX3: libc::c_int = 111;
 
+// Sentinel Association IDs
+pub(crate) const SCTP_FUTURE_ASSOC: i32 = 0;
+pub(crate) const SCTP_CURRENT_ASSOC: i32 = 1;
+pub(crate) const SCTP_ALL_ASSOC: i32 = 2;
+
 // To subscribe to SCTP Events
 pub(crate) static SCTP_EVENT: libc::c_int = 127;
 
 //
 pub(crate) static MSG_NOTIFICATION: u32 = 0x8000;
 
+// Flags used in `SendInfo` (`snd_flags`). See Section 5.3.4 of RFC 6458.
+pub(crate) const SCTP_UNORDERED: u16 = 1 << 0;
+pub(crate) const SCTP_ADDR_OVER: u16 = 1 << 1;
+pub(crate) const SCTP_ABORT: u16 = 1 << 2;
+pub(crate) const SCTP_SACK_IMMEDIATELY: u16 = 1 << 3;
+pub(crate) const SCTP_SENDALL: u16 = 1 << 6;
+pub(crate) const SCTP_EOF: u16 = libc::MSG_FIN as u16;
+
 // Notification Types Constants
+pub(crate) const SCTP_DATA_IO_EVENT: u16 = 1 << 15;
 pub(crate) const SCTP_ASSOC_CHANGE: u16 = (1 << 15) + 0x0001;
 pub(crate) const SCTP_PEER_ADDR_CHANGE: u16 = (1 << 15) + 0x0002;
 pub(crate) const SCTP_SEND_FAILED: u16 = (1 << 15) + 0x0003;
@@ -34,6 +51,73 @@ pub(crate) const SCTP_PARTIAL_DELIVERY_EVENT: u16 = (1 << 15) + 0x0006;
 pub(crate) const SCTP_ADAPTATION_INDICATION: u16 = (1 << 15) + 0x0007;
 pub(crate) const SCTP_AUTHENTICATION_EVENT: u16 = (1 << 15) + 0x0008;
 pub(crate) const SCTP_SENDER_DRY_EVENT: u16 = (1 << 15) + 0x0009;
+pub(crate) const SCTP_STREAM_RESET_EVENT: u16 = (1 << 15) + 0x000A;
+pub(crate) const SCTP_ASSOC_RESET_EVENT: u16 = (1 << 15) + 0x000B;
+pub(crate) const SCTP_STREAM_CHANGE_EVENT: u16 = (1 << 15) + 0x000C;
+pub(crate) const SCTP_SEND_FAILED_EVENT: u16 = (1 << 15) + 0x000D;
+
+// Indication of the Partial Delivery Event Notification. See Section 6.1.7 of RFC 6458.
+pub(crate) const SCTP_PARTIAL_DELIVERY_ABORTED: u32 = 0;
+
+// Features supported by the association, reported in `sac_info` of `SCTP_COMM_UP` and
+// `SCTP_RESTART` notifications. See Section 6.1.1 of RFC 6458.
+pub(crate) const SCTP_ASSOC_SUPPORTS_PR: u8 = 0x01;
+pub(crate) const SCTP_ASSOC_SUPPORTS_AUTH: u8 = 0x02;
+pub(crate) const SCTP_ASSOC_SUPPORTS_ASCONF: u8 = 0x03;
+pub(crate) const SCTP_ASSOC_SUPPORTS_MULTIBUF: u8 = 0x04;
+pub(crate) const SCTP_ASSOC_SUPPORTS_RE_CONFIG: u8 = 0x05;
+
+// Retransmission Timeout parameters
+pub(crate) const SCTP_RTOINFO: libc::c_int = 0;
+
+// Association parameters
+pub(crate) const SCTP_ASSOCINFO: libc::c_int = 1;
+
+// Automatic close of idle associations on One to Many sockets
+pub(crate) const SCTP_AUTOCLOSE: libc::c_int = 4;
+
+// Information about a Peer Address
+pub(crate) const SCTP_GET_PEER_ADDR_INFO: libc::c_int = 15;
+
+// Disable fragmentation of the user messages
+pub(crate) const SCTP_DISABLE_FRAGMENTS: libc::c_int = 8;
+
+// Maximum fragment size
+pub(crate) const SCTP_MAXSEG: libc::c_int = 13;
+
+// Fragment interleaving and User Message Interleaving (I-DATA, RFC 8260) support
+pub(crate) const SCTP_FRAGMENT_INTERLEAVE: libc::c_int = 18;
+pub(crate) const SCTP_INTERLEAVING_SUPPORTED: libc::c_int = 125;
+
+// Disable the Nagle like algorithm
+pub(crate) const SCTP_NODELAY: libc::c_int = 3;
+
+// Delayed SACK parameters
+pub(crate) const SCTP_DELAYED_SACK: libc::c_int = 16;
+
+// Statistics of an association
+pub(crate) const SCTP_GET_ASSOC_STATS: libc::c_int = 112;
+
+// Default Partial Reliability (PR-SCTP, RFC 3758) policy and its policies (See Section 4 of RFC
+// 7496)
+pub(crate) const SCTP_DEFAULT_PRINFO: libc::c_int = 114;
+pub(crate) const SCTP_PR_SCTP_NONE: u16 = 0x0000;
+pub(crate) const SCTP_PR_SCTP_TTL: u16 = 0x0010;
+
+// Stream scheduler and the priority scheduler (RFC 8260)
+pub(crate) const SCTP_STREAM_SCHEDULER: libc::c_int = 123;
+pub(crate) const SCTP_SS_PRIO: u32 = 1;
+
+// Adaptation Layer Indication sent in the `INIT` / `INIT-ACK`
+pub(crate) const SCTP_ADAPTATION_LAYER: libc::c_int = 7;
+
+// Dynamic Address Reconfiguration (ASCONF, RFC 5061) support
+pub(crate) const SCTP_ASCONF_SUPPORTED: libc::c_int = 128;
+
+// Authentication of chunks (See Section 8.3.1 to 8.3.3 of RFC 6458)
+pub(crate) const SCTP_AUTH_CHUNK: libc::c_int = 21;
+pub(crate) const SCTP_AUTH_KEY: libc::c_int = 23;
+pub(crate) const SCTP_AUTH_ACTIVE_KEY: libc::c_int = 24;
 
 // Init Message used for `setsockopt`
 pub(crate) const SCTP_INITMSG: libc::c_int = 2;
@@ -45,3 +129,25 @@ pub(crate) const SCTP_DEFAULT_SNDINFO: libc::c_int = 34;
 
 // Get SCTP Status
 pub(crate) const SCTP_STATUS: libc::c_int = 14;
+
+// Remote UDP encapsulation port (SCTP over UDP, RFC 6951)
+#[cfg(target_os = "linux")]
+pub(crate) const SCTP_REMOTE_UDP_ENCAPS_PORT: libc::c_int = 132;
+
+// Set the primary address of the peer
+pub(crate) const SCTP_PRIMARY_ADDR: libc::c_int = 6;
+
+// Peer Address Parameters
+pub(crate) const SCTP_PEER_ADDR_PARAMS: libc::c_int = 9;
+
+// Flags used in `PeerAddrParams` (`spp_flags`). See Section 8.1.12 of RFC 6458.
+pub(crate) const SPP_HB_ENABLE: u32 = 1 << 0;
+pub(crate) const SPP_HB_DISABLE: u32 = 1 << 1;
+pub(crate) const SPP_HB_DEMAND: u32 = 1 << 2;
+pub(crate) const SPP_PMTUD_ENABLE: u32 = 1 << 3;
+pub(crate) const SPP_PMTUD_DISABLE: u32 = 1 << 4;
+pub(crate) const SPP_SACKDELAY_ENABLE: u32 = 1 << 5;
+pub(crate) const SPP_SACKDELAY_DISABLE: u32 = 1 << 6;
+pub(crate) const SPP_HB_TIME_IS_ZERO: u32 = 1 << 7;
+pub(crate) const SPP_IPV6_FLOWLABEL: u32 = 1 << 8;
+pub(crate) const SPP_DSCP: u32 = 1 << 9;
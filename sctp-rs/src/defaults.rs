@@ -0,0 +1,74 @@
+//! Default values used by the kernel for the new associations.
+
+use tokio::io::unix::AsyncFd;
+
+use std::os::unix::io::RawFd;
+
+use crate::internal::*;
+use crate::{AssocInfo, RtoInfo, SocketToAssociation};
+
+/// Default values used by the kernel for the new associations.
+///
+/// These are the values the new associations use, unless they are overridden on the socket (eg.
+/// using [`sctp_setup_init_params`][crate::Socket::sctp_setup_init_params]). On Linux, these are
+/// determined by the `net.sctp` sysctls.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SctpDefaults {
+    /// Default Retransmission Timeout (RTO) parameters.
+    pub rto_info: RtoInfo,
+
+    /// Default association parameters.
+    pub assoc_info: AssocInfo,
+
+    /// Default number of the outbound streams requested.
+    pub ostreams: u16,
+
+    /// Default maximum number of the inbound streams.
+    pub istreams: u16,
+
+    /// Default maximum number of retransmissions of the `INIT`.
+    pub init_retries: u16,
+
+    /// Default maximum RTO (in milliseconds) used while retransmitting the `INIT`.
+    pub init_timeout: u16,
+}
+
+impl SctpDefaults {
+    /// Read the defaults.
+    ///
+    /// The values are read from a new socket, that is closed right after, the socket is never
+    /// bound or connected. Like creating any other socket, this should be called from within the
+    /// `tokio` runtime.
+    pub fn read() -> std::io::Result<Self> {
+        let rawfd = sctp_socket_internal(libc::AF_INET, SocketToAssociation::OneToMany)?;
+        let fd = match AsyncFd::new(rawfd) {
+            Ok(fd) => fd,
+            Err(e) => {
+                // Safety: The `rawfd` is valid and not used after this.
+                unsafe {
+                    libc::close(rawfd);
+                }
+                return Err(e);
+            }
+        };
+
+        let defaults = read_defaults(&fd);
+        close_internal(&fd);
+
+        defaults
+    }
+}
+
+fn read_defaults(fd: &AsyncFd<RawFd>) -> std::io::Result<SctpDefaults> {
+    let init_params = sctp_get_init_params_internal(fd)?;
+
+    Ok(SctpDefaults {
+        rto_info: sctp_get_rto_info_internal(fd, 0.into())?,
+        assoc_info: sctp_get_assoc_info_internal(fd, 0.into())?,
+        ostreams: init_params.ostreams,
+        istreams: init_params.istreams,
+        init_retries: init_params.retries,
+        init_timeout: init_params.timeout,
+    })
+}
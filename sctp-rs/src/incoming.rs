@@ -0,0 +1,80 @@
+//! Receiving on an SCTP Socket until the association is closed.
+
+use tokio::io::unix::AsyncFd;
+
+use std::os::unix::io::RawFd;
+
+use crate::internal::sctp_recvmsg_reassembled_internal;
+use crate::types::internal::{Reassembly, ReceiveState};
+use crate::{AssocChangeState, AssociationChange, Notification, NotificationOrData};
+
+/// An adaptor that receives Data or Notifications until the association is closed.
+///
+/// This structure is created by [`ConnectedSocket::incoming`][crate::ConnectedSocket::incoming]
+/// or [`OneToOneSocket::incoming`][crate::OneToOneSocket::incoming] and is used as follows -
+///
+/// ```rust,no_run
+/// # async fn receive(socket: sctp_rs::ConnectedSocket) {
+/// let mut incoming = socket.incoming();
+/// while let Some(received) = incoming.next().await {
+///     match received {
+///         Ok(notification_or_data) => { /* Process Notification or Data */ }
+///         Err(e) => { /* Decide whether to continue */ }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Incoming<'a> {
+    inner: &'a AsyncFd<RawFd>,
+    state: &'a ReceiveState,
+    done: bool,
+}
+
+impl<'a> Incoming<'a> {
+    pub(crate) fn new(inner: &'a AsyncFd<RawFd>, state: &'a ReceiveState) -> Self {
+        Self {
+            inner,
+            state,
+            done: false,
+        }
+    }
+
+    /// Receive the next Data or Notification.
+    ///
+    /// Returns `None` after a [`Shutdown`][crate::Notification::Shutdown] notification or an
+    /// [`AssociationChange`][crate::Notification::AssociationChange] notification with the state
+    /// [`CommLost`][crate::AssocChangeState::CommLost] is returned (note: these notifications are
+    /// received only when subscribed to) or when the peer has closed the association. Any other
+    /// errors are returned as `Err` items and receiving can be continued.
+    ///
+    /// The messages are received the same way as by the `sctp_recv` of the socket, eg. the
+    /// messages received by [`ConnectedSocket::incoming`][crate::ConnectedSocket::incoming] are
+    /// reassembled (See [`ConnectedSocket::sctp_recv`][crate::ConnectedSocket::sctp_recv]).
+    pub async fn next(&mut self) -> Option<std::io::Result<NotificationOrData>> {
+        if self.done {
+            return None;
+        }
+
+        match sctp_recvmsg_reassembled_internal(self.inner, self.state, Reassembly::Configured)
+            .await
+        {
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                self.done = true;
+                None
+            }
+            Ok(NotificationOrData::Notification(notification)) => {
+                self.done = matches!(
+                    notification,
+                    Notification::Shutdown(_)
+                        | Notification::AssociationChange(AssociationChange {
+                            state: AssocChangeState::CommLost,
+                            ..
+                        })
+                );
+                Some(Ok(NotificationOrData::Notification(notification)))
+            }
+            result => Some(result),
+        }
+    }
+}
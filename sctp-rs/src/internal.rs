@@ -12,12 +12,18 @@ use std::os::unix::io::{AsRawFd, RawFd};
 use os_socketaddr::OsSocketAddr;
 
 use crate::types::internal::{
-    ConnStatusInternal, ConnectxParam, GetAddrs, InitMsg, SubscribeEvent,
+    sockaddr_storage_from, AssocStats, AssocValue, AuthKeyId, AuthKeys, ConnStatusInternal,
+    ConnectxParam, DefaultPrInfo, GetAddrs, InitMsg, PeerAddrInfoInternal, PeerAddrParamsInternal,
+    Reassembly, ReceiveState, ResubscribeEvents, SackInfo, SendBlocked, SentSsns, SetAdaptation,
+    SetPrimaryInternal, SndRcvInfo, SubscribeEvent,
 };
+use crate::types::{ConnState, NOTIFICATION_EVENTS};
 use crate::{
-    AssocChangeState, AssociationChange, AssociationId, BindxFlags, CmsgType, ConnStatus,
-    ConnectedSocket, Event, Listener, Notification, NotificationOrData, NxtInfo, RcvInfo,
-    ReceivedData, SendData, SendInfo, Shutdown, SubscribeEventAssocId,
+    AdaptationEvent, AssocChangeState, AssocInfo, AssociationChange, AssociationId, AuthKeyState,
+    BindxFlags, CmsgType, ConnStatus, ConnectedSocket, Event, HandshakeParams, Listener,
+    Notification, NotificationOrData, NxtInfo, PdapiEvent, PeerAddrChange, PeerAddrParams,
+    PeerAddrState, RcvInfo, ReceivedData, ReceivedMessageInfo, RtoInfo, SctpError, SendData,
+    SendFailed, SendInfo, Shutdown, SubscribeEventAssocId,
 };
 
 #[allow(unused)]
@@ -25,6 +31,24 @@ use super::consts::*;
 
 static SOL_SCTP: libc::c_int = 132;
 
+// SACK delay (in milliseconds) used by `configure_bounded_latency`, the default is 200ms.
+const BOUNDED_LATENCY_SACK_DELAY: u32 = 10;
+
+// Minimum size of the buffer used to receive the messages.
+const RECV_BUFFER_SIZE: usize = 4096;
+
+// Interval at which the association state is checked while waiting for it to be established, it
+// is doubled after every check up to `CONNECT_POLL_INTERVAL_MAX`.
+const CONNECT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+const CONNECT_POLL_INTERVAL_MAX: std::time::Duration = std::time::Duration::from_millis(64);
+
+// A send taking at least this long is considered blocked by the flow control.
+
+// Interval at which the association statistics are checked while waiting for a heartbeat ack, it
+// is doubled after every check up to `PING_POLL_INTERVAL_MAX`.
+const PING_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(1);
+const PING_POLL_INTERVAL_MAX: std::time::Duration = std::time::Duration::from_millis(16);
+
 // Implementation of `sctp_bindx` using `libc::setsockopt`
 pub(crate) fn sctp_bindx_internal(
     fd: &AsyncFd<RawFd>,
@@ -33,6 +57,8 @@ pub(crate) fn sctp_bindx_internal(
 ) -> std::io::Result<()> {
     log::debug!("Binding following addresses to socket: {:#?}", addrs);
 
+    check_address_family_internal(fd, addrs)?;
+
     let mut addrs_u8: Vec<u8> = vec![];
 
     for addr in addrs {
@@ -78,11 +104,146 @@ pub(crate) fn sctp_bindx_internal(
     }
 }
 
+// Check that all the addresses can be bound on the socket. The IPv6 addresses cannot be bound on an
+// IPv4 socket and the IPv4 addresses cannot be bound on an IPv6 socket that is `IPV6_V6ONLY`. The
+// kernel returns an `EINVAL` for the whole call otherwise, which does not tell the cause.
+fn check_address_family_internal(fd: &AsyncFd<RawFd>, addrs: &[SocketAddr]) -> std::io::Result<()> {
+    let family = socket_family_internal(fd)?;
+    let mismatched: Vec<SocketAddr> = if family == libc::AF_INET {
+        addrs
+            .iter()
+            .filter(|addr| addr.is_ipv6())
+            .copied()
+            .collect()
+    } else if addrs.iter().any(|addr| addr.is_ipv4()) {
+        let mut v6only: libc::c_int = 0;
+        getsockopt_internal(fd, libc::IPPROTO_IPV6, libc::IPV6_V6ONLY, &mut v6only)?;
+        if v6only != 0 {
+            addrs
+                .iter()
+                .filter(|addr| addr.is_ipv4())
+                .copied()
+                .collect()
+        } else {
+            vec![]
+        }
+    } else {
+        vec![]
+    };
+
+    if mismatched.is_empty() {
+        Ok(())
+    } else {
+        Err(SctpError::AddressFamilyMismatch {
+            family,
+            addrs: mismatched,
+        }
+        .into())
+    }
+}
+
+// Get the address family of the socket.
+#[cfg(target_os = "linux")]
+fn socket_family_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<libc::c_int> {
+    let mut family: libc::c_int = 0;
+    getsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_DOMAIN, &mut family)?;
+
+    Ok(family)
+}
+
+// Get the address family of the socket. `getsockname` reports the family of a socket that is not
+// bound yet as well.
+#[cfg(not(target_os = "linux"))]
+fn socket_family_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<libc::c_int> {
+    // Safety: An all zeroes `sockaddr_storage` is valid and it is valid in the current scope.
+    unsafe {
+        let mut address: libc::sockaddr_storage = std::mem::zeroed();
+        let mut address_len = std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+        let result = libc::getsockname(
+            *fd.get_ref(),
+            &mut address as *mut _ as *mut libc::sockaddr,
+            &mut address_len,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(address.ss_family as libc::c_int)
+        }
+    }
+}
+
+// Whether the socket is an SCTP socket, ie. its protocol (`SO_PROTOCOL`) is `IPPROTO_SCTP`.
+#[cfg(target_os = "linux")]
+pub(crate) fn is_sctp_internal(fd: &AsyncFd<RawFd>) -> bool {
+    let mut protocol: libc::c_int = 0;
+    match getsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_PROTOCOL, &mut protocol) {
+        Ok(()) => protocol == libc::IPPROTO_SCTP,
+        Err(e) => {
+            log::debug!("Getting `SO_PROTOCOL` failed: {}", e);
+            false
+        }
+    }
+}
+
+// Whether the socket is an SCTP socket. `SO_PROTOCOL` is not available, an `IPPROTO_SCTP` level
+// socket option is supported only by the SCTP sockets.
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn is_sctp_internal(fd: &AsyncFd<RawFd>) -> bool {
+    let mut on: libc::c_int = 0;
+    sctp_getsockopt_internal(fd, SCTP_NODELAY, &mut on).is_ok()
+}
+
+// Get the local address of the socket using `getsockname`. For a socket bound to multiple
+// addresses, this is the primary (first bound) address.
+pub(crate) fn local_addr_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<SocketAddr> {
+    // Safety: An all zeroes `sockaddr_storage` is valid and it is valid in the current scope.
+    unsafe {
+        let mut address: libc::sockaddr_storage = std::mem::zeroed();
+        let mut address_len = std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+        let result = libc::getsockname(
+            *fd.get_ref(),
+            &mut address as *mut _ as *mut libc::sockaddr,
+            &mut address_len,
+        );
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        OsSocketAddr::copy_from_raw(&address as *const _ as *const libc::sockaddr, address_len)
+            .into_addr()
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "Unsupported address family of the local address.",
+                )
+            })
+    }
+}
+
+// Implementation of `sctp_bindx` on an established association.
+//
+// The addresses are packed the same way as for an unconnected socket, each `sockaddr` of its own
+// size (`sockaddr_in` or `sockaddr_in6`) one after the other, the kernel then sends the `ASCONF`
+// chunk to the peer adding (or deleting) the addresses. This is possible only if the Dynamic
+// Address Reconfiguration (RFC 5061) is negotiated with the peer, which is checked first, since
+// otherwise the kernel only updates the local addresses silently.
+pub(crate) fn sctp_bindx_connected_internal(
+    fd: &AsyncFd<RawFd>,
+    addrs: &[SocketAddr],
+    flags: BindxFlags,
+) -> std::io::Result<()> {
+    if sctp_get_assoc_value_internal(fd, SCTP_ASCONF_SUPPORTED, 0.into())? == 0 {
+        return Err(SctpError::AsconfNotSupported.into());
+    }
+
+    sctp_bindx_internal(fd, addrs, flags)
+}
+
 // Implementation of `sctp_peeloff` using `libc::getsockopt`
 pub(crate) fn sctp_peeloff_internal(
     fd: &AsyncFd<RawFd>,
     assoc_id: AssociationId,
-) -> std::io::Result<ConnectedSocket> {
+) -> std::io::Result<RawFd> {
     log::debug!("Peeling off socket for Association ID: {:?}", assoc_id);
 
     use crate::types::internal::PeeloffArg;
@@ -114,7 +275,7 @@ pub(crate) fn sctp_peeloff_internal(
             log::debug!("Setting peeled off socket to non-blocking.");
             set_fd_non_blocking(rawfd)?;
 
-            ConnectedSocket::from_rawfd(rawfd)
+            Ok(rawfd)
         }
     }
 }
@@ -138,9 +299,21 @@ pub(crate) fn sctp_socket_internal(
                 libc::socket(domain, libc::SOCK_SEQPACKET, libc::IPPROTO_SCTP)
             }
         };
+        if rawfd < 0 {
+            let errno = std::io::Error::last_os_error().raw_os_error().unwrap_or(0);
+            return Err(SctpError::SocketCreate {
+                family: domain,
+                style: assoc,
+                errno,
+            }
+            .into());
+        }
 
         log::debug!("Setting 'socket' to Non-blocking socket.");
-        set_fd_non_blocking(rawfd)?;
+        if let Err(e) = set_fd_non_blocking(rawfd) {
+            libc::close(rawfd);
+            return Err(e);
+        }
 
         Ok(rawfd)
     }
@@ -148,9 +321,15 @@ pub(crate) fn sctp_socket_internal(
 
 // Implementation of `listen` using `libc::listen`
 pub(crate) fn sctp_listen_internal(fd: AsyncFd<RawFd>, backlog: i32) -> std::io::Result<Listener> {
+    listen_internal(&fd, backlog)?;
+    Listener::from_rawfd(fd.into_inner())
+}
+
+// Actual `libc::listen` call, without consuming the `fd`. One to Many sockets keep using the same
+// socket after `listen`.
+pub(crate) fn listen_internal(fd: &AsyncFd<RawFd>, backlog: i32) -> std::io::Result<()> {
     unsafe {
-        let rawfd = *fd.get_ref();
-        let result = libc::listen(rawfd, backlog);
+        let result = libc::listen(*fd.get_ref(), backlog);
 
         if result < 0 {
             log::error!(
@@ -159,7 +338,7 @@ pub(crate) fn sctp_listen_internal(fd: AsyncFd<RawFd>, backlog: i32) -> std::io:
             );
             Err(std::io::Error::last_os_error())
         } else {
-            Listener::from_rawfd(fd.into_inner())
+            Ok(())
         }
     }
 }
@@ -270,11 +449,60 @@ fn sctp_getaddrs_internal(
     }
 }
 
+// Get all the Association IDs on a One to Many socket using `SCTP_GET_ASSOC_ID_LIST`.
+//
+// The kernel returns `EINVAL` if the buffer is not large enough for all the Association IDs, in
+// which case we retry with a larger buffer.
+pub(crate) fn sctp_get_assoc_ids_internal(
+    fd: &AsyncFd<RawFd>,
+) -> std::io::Result<Vec<AssociationId>> {
+    log::debug!("Getting Association IDs using `getsockopt`.");
+
+    let id_size = std::mem::size_of::<AssociationId>();
+    let mut capacity = 64_usize;
+    loop {
+        // First `u32` is the number of IDs followed by the IDs themselves.
+        let mut ids_buff: Vec<u8> = vec![0; std::mem::size_of::<u32>() + capacity * id_size];
+        let mut ids_size = ids_buff.len() as libc::socklen_t;
+
+        // Safety: `ids_buff` and `ids_size` are valid in the current scope.
+        let result = unsafe {
+            libc::getsockopt(
+                *fd.get_ref(),
+                SOL_SCTP,
+                SCTP_GET_ASSOC_ID_LIST,
+                ids_buff.as_mut_ptr() as *mut libc::c_void,
+                &mut ids_size as *mut _ as *mut libc::socklen_t,
+            )
+        };
+
+        if result < 0 {
+            let last_error = std::io::Error::last_os_error();
+            if last_error.raw_os_error() == Some(libc::EINVAL) && capacity < 65536 {
+                capacity *= 2;
+                continue;
+            }
+            log::error!(
+                "Error: {} during `getsockopt` for `SCTP_GET_ASSOC_ID_LIST`.",
+                last_error
+            );
+            return Err(last_error);
+        }
+
+        let count = u32::from_ne_bytes(ids_buff[0..4].try_into().unwrap()) as usize;
+        return Ok(ids_buff[4..]
+            .chunks_exact(id_size)
+            .take(count)
+            .map(|id| i32::from_ne_bytes(id.try_into().unwrap()).into())
+            .collect());
+    }
+}
+
 // Implementation of `sctp_connectx` using `getsockopt` and new API using `SCTP_SOCKOPT_CONNECTX3`.
 pub(crate) async fn sctp_connectx_internal(
     fd: AsyncFd<RawFd>,
     addrs: &[SocketAddr],
-) -> std::io::Result<(ConnectedSocket, AssociationId)> {
+) -> std::io::Result<(RawFd, AssociationId)> {
     let mut addrs_u8: Vec<u8> = vec![];
 
     log::debug!("Connecting to {:?} using `getsockopt`", addrs);
@@ -292,7 +520,7 @@ pub(crate) async fn sctp_connectx_internal(
     // to raw data is valid.
     unsafe {
         let mut params = ConnectxParam {
-            assoc_id: 0,
+            assoc_id: 0.into(),
             addrs_size: addrs_len.try_into().unwrap(),
             addrs: addrs_u8.as_mut_ptr(),
         };
@@ -348,13 +576,13 @@ pub(crate) async fn sctp_connectx_internal(
         );
 
         // We can (and should) now 'consume' the passed `fd` or else 'registration' of next
-        // `ConnectedSocket` (during `AsyncFd::new` would fail. Consuming the `AsyncFd` would
+        // `ConnectedSocket` (or `OneToOneSocket`) during `AsyncFd::new` would fail. Consuming the `AsyncFd` would
         // de-register.)
         // Also, since this `fd` is the 'original' created with `socket` call, no need to set it to
         // non-blocking again.
         let rawfd = fd.into_inner();
 
-        Ok((ConnectedSocket::from_rawfd(rawfd)?, params.assoc_id))
+        Ok((rawfd, params.assoc_id))
     }
 }
 
@@ -362,17 +590,33 @@ pub(crate) async fn sctp_connectx_internal(
 // type is not the right one (UDP Style `SOCK_SEQPACKET`).
 pub(crate) async fn accept_internal(
     fd: &AsyncFd<RawFd>,
+) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
+    // This is ugly for the following reasons - On the `SEQPACKET` sockets, we do not get
+    // `readable` ready at all for the `accept`.  (Why not sure? Even when tried after sending
+    // some dummy data to make sure we can recv on it.) Thus we try `accept` first for
+    // `SEQPACKET` sockets, this `accept` would fail with `EINVAL` and for `STREAM` sockets,
+    // this 'may' fail with `EWOULDBLOCK`. If it does, we wait for `readable` event again, in
+    // the next iteration of the `loop`, we won't get `EWOULDBLOCK` and will actually `accept`.
+    loop {
+        match try_accept_internal(fd) {
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                // We got an `EWOULDBLOCK` let's wait.
+                fd.readable().await?.clear_ready();
+            }
+            result => return result,
+        }
+    }
+}
+
+// Non-blocking `accept`, returns an error of the kind `WouldBlock` when there is no pending
+// connection. `accept` interrupted by a signal (`EINTR`) is retried.
+pub(crate) fn try_accept_internal(
+    fd: &AsyncFd<RawFd>,
 ) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
     // Safety: Both `addrs_buff` and `addrs_len` are in the scope and hence are valid pointers.
     unsafe {
         let raw_fd = *fd.get_ref();
 
-        // This is ugly for the following reasons - On the `SEQPACKET` sockets, we do not get
-        // `readable` ready at all for the `accept`.  (Why not sure? Even when tried after sending
-        // some dummy data to make sure we can recv on it.) Thus we try `accept` first for
-        // `SEQPACKET` sockets, this `accept` would fail with `EINVAL` and for `STREAM` sockets,
-        // this 'may' fail with `EWOULDBLOCK`. If it does, we wait for `readable` event again, in
-        // the next iteration of the `loop`, we won't get `EWOULDBLOCK` and will actually `accept`.
         loop {
             // this should be enough to `accept` a connection normally `sockaddr`s maximum size is
             // 28 for the `sa_family` we care about.
@@ -392,16 +636,14 @@ pub(crate) async fn accept_internal(
 
             if result < 0 {
                 let last_error = std::io::Error::last_os_error();
-                if last_error.raw_os_error() != Some(libc::EWOULDBLOCK) {
-                    log::error!(
-                        "Error: '{}' while `accept`ing on the socket.",
-                        std::io::Error::last_os_error()
-                    );
-                    return Err(last_error);
+                match last_error.raw_os_error() {
+                    Some(libc::EINTR) => continue,
+                    Some(libc::EWOULDBLOCK) => return Err(last_error),
+                    _ => {
+                        log::error!("Error: '{}' while `accept`ing on the socket.", last_error);
+                        return Err(last_error);
+                    }
                 }
-
-                // We got an `EWOULDBLOCK` let's wait.
-                fd.readable().await?.clear_ready();
             } else {
                 let os_socketaddr = OsSocketAddr::copy_from_raw(
                     addrs_buff.as_ptr() as *const _ as *const libc::sockaddr,
@@ -425,6 +667,127 @@ pub(crate) async fn accept_internal(
     }
 }
 
+// Connect and wait until the association is up, but not longer than the `timeout` if given.
+//
+// `wait_up` is called with the `ConnectedSocket` right after connecting, to wait until the
+// association is up. The socket is closed if connecting fails or the `timeout` expires, in which
+// case a `TimedOut` error is returned.
+async fn sctp_connectx_deadline_internal<T, F, Fut>(
+    fd: AsyncFd<RawFd>,
+    addrs: &[SocketAddr],
+    timeout: Option<std::time::Duration>,
+    wait_up: F,
+) -> std::io::Result<(ConnectedSocket, T)>
+where
+    F: FnOnce(ConnectedSocket, AssociationId) -> Fut,
+    Fut: std::future::Future<Output = std::io::Result<(ConnectedSocket, T)>>,
+{
+    let rawfd = *fd.get_ref();
+    // Whether the `fd` is closed already, or owned by the `ConnectedSocket` (and hence closed when
+    // it's dropped).
+    let owned = std::cell::Cell::new(false);
+
+    let connect = async {
+        // `sctp_connectx_internal` closes the `fd` itself, if connecting fails.
+        let result = sctp_connectx_internal(fd, addrs).await;
+        owned.set(true);
+        let (rawfd, assoc_id) = result?;
+
+        let connected = match ConnectedSocket::from_rawfd(rawfd) {
+            Ok(connected) => connected,
+            Err(e) => {
+                // Safety: The `fd` is not closed yet and is not used after this.
+                unsafe {
+                    libc::close(rawfd);
+                }
+                return Err(e);
+            }
+        };
+        wait_up(connected, assoc_id).await
+    };
+
+    let result = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, connect).await,
+        None => Ok(connect.await),
+    };
+
+    match result {
+        Ok(result) => result,
+        Err(_) => {
+            if !owned.get() {
+                // Safety: The `fd` is not closed yet and is not used after this.
+                unsafe {
+                    libc::close(rawfd);
+                }
+            }
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "Association not established within the timeout.",
+            ))
+        }
+    }
+}
+
+// Connect and wait until the association is established, but not longer than the `timeout`.
+//
+// The socket becomes writable as soon as the handshake is started, so the state of the
+// association is polled after that until it is `Established`, backing off from every
+// `CONNECT_POLL_INTERVAL` to every `CONNECT_POLL_INTERVAL_MAX`. An error is returned right away,
+// if the association is closed (or gone) instead.
+pub(crate) async fn sctp_connectx_timeout_internal(
+    fd: AsyncFd<RawFd>,
+    addrs: &[SocketAddr],
+    timeout: std::time::Duration,
+) -> std::io::Result<(ConnectedSocket, AssociationId)> {
+    sctp_connectx_deadline_internal(fd, addrs, Some(timeout), |connected, assoc_id| async move {
+        let mut interval = CONNECT_POLL_INTERVAL;
+        loop {
+            match connected.sctp_get_status(assoc_id)?.state {
+                ConnState::Established => return Ok((connected, assoc_id)),
+                ConnState::CookieWait | ConnState::CookieEchoed => {}
+                state => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::ConnectionRefused,
+                        format!("Association not established: {:?}", state),
+                    ))
+                }
+            }
+            tokio::time::sleep(interval).await;
+            interval = (interval * 2).min(CONNECT_POLL_INTERVAL_MAX);
+        }
+    })
+    .await
+}
+
+// Connect implementation for the `ClientBuilder`. The `Association` event needs to be subscribed
+// before connecting, the association is returned only after the `CommUp` is received.
+pub(crate) async fn sctp_connectx_comm_up_internal(
+    fd: AsyncFd<RawFd>,
+    addrs: &[SocketAddr],
+    timeout: Option<std::time::Duration>,
+) -> std::io::Result<(ConnectedSocket, AssociationChange)> {
+    sctp_connectx_deadline_internal(fd, addrs, timeout, |connected, _| async move {
+        loop {
+            match connected.sctp_recv().await? {
+                NotificationOrData::Notification(Notification::AssociationChange(change)) => {
+                    match change.state {
+                        AssocChangeState::CommUp => return Ok((connected, change)),
+                        AssocChangeState::CannotStartAssoc | AssocChangeState::CommLost => {
+                            return Err(std::io::Error::new(
+                                std::io::ErrorKind::ConnectionRefused,
+                                format!("Association not established: {:?}", change.state),
+                            ))
+                        }
+                        _ => log::debug!("Ignoring association change: {:#?}", change),
+                    }
+                }
+                other => log::debug!("Ignoring before the association is up: {:#?}", other),
+            }
+        }
+    })
+    .await
+}
+
 // Shutdown implementation for `Listener` and `ConnectedSocket`.
 pub(crate) fn shutdown_internal(
     fd: &AsyncFd<RawFd>,
@@ -451,145 +814,451 @@ pub(crate) fn shutdown_internal(
     }
 }
 
-// Implementation for the receive side for SCTP.
-// TODO: Handle Control Message Header
-pub(crate) async fn sctp_recvmsg_internal(
+// Receive into the buffer on a socket that re-subscribes to the events of an association when it
+// is restarted. The messages held in the `state` are returned first.
+pub(crate) async fn sctp_recvmsg_into_resubscribe_internal(
     fd: &AsyncFd<RawFd>,
-) -> std::io::Result<NotificationOrData> {
+    state: &ReceiveState,
+    recv_buffer: &mut Vec<u8>,
+) -> std::io::Result<ReceivedMessageInfo> {
+    let info = match state.pending.pop() {
+        Some((info, pending)) => {
+            *recv_buffer = pending;
+            info
+        }
+        None => sctp_recvmsg_into_internal(fd, recv_buffer).await?,
+    };
+    if info.notification {
+        resubscribe_on_restart_internal(
+            fd,
+            &state.resubscribe,
+            &notification_from_message(recv_buffer),
+        );
+    }
+
+    Ok(info)
+}
+
+// The subscriptions of an association may be reset when the association is restarted, subscribe
+// to the `resubscribe` events again for the restarted association. Failures are only logged, the
+// received notification is returned to the caller regardless.
+fn resubscribe_on_restart_internal(
+    fd: &AsyncFd<RawFd>,
+    resubscribe: &ResubscribeEvents,
+    notification: &Notification,
+) {
+    if let Notification::AssociationChange(AssociationChange {
+        state: AssocChangeState::Restart,
+        assoc_id,
+        ..
+    }) = notification
+    {
+        for event in resubscribe.get() {
+            log::debug!(
+                "Re-subscribing to {:?} for the restarted association: {}",
+                event,
+                assoc_id
+            );
+            let result = sctp_subscribe_event_internal(
+                fd,
+                event,
+                SubscribeEventAssocId::Value(*assoc_id),
+                true,
+            );
+            if let Err(e) = result {
+                log::error!(
+                    "Error: {} re-subscribing for the association: {}",
+                    e,
+                    assoc_id
+                );
+            }
+        }
+    }
+}
+
+// Receive a Notification or (part of a) Data message. Also returns whether the end of the record
+// (`MSG_EOR`) is received, ie. whether this is the last part of the message. A message larger than
+// the receive buffer is received in multiple parts.
+async fn sctp_recvmsg_eor_internal(
+    fd: &AsyncFd<RawFd>,
+) -> std::io::Result<(NotificationOrData, bool)> {
+    let mut recv_buffer = vec![];
+    let info = sctp_recvmsg_into_internal(fd, &mut recv_buffer).await?;
+    let eor = info.eor;
+
+    Ok((received_message(info, recv_buffer), eor))
+}
+
+// Non-blocking receive of a Notification or (part of a) Data message, along with whether the end
+// of the record is received. Returns `None` when there is nothing to receive.
+fn sctp_try_recvmsg_eor_internal(
+    fd: &AsyncFd<RawFd>,
+) -> std::io::Result<Option<(NotificationOrData, bool)>> {
+    let mut recv_buffer = vec![];
+    match sctp_try_recvmsg_into_internal(fd, &mut recv_buffer) {
+        Ok(info) => {
+            let eor = info.eor;
+            Ok(Some((received_message(info, recv_buffer), eor)))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Build the Notification or Data from the received message.
+fn received_message(info: ReceivedMessageInfo, recv_buffer: Vec<u8>) -> NotificationOrData {
+    if info.notification {
+        NotificationOrData::Notification(notification_from_message(&recv_buffer))
+    } else {
+        NotificationOrData::Data(ReceivedData {
+            payload: recv_buffer,
+            rcv_info: info.rcv_info,
+            nxt_info: info.nxt_info,
+        })
+    }
+}
+
+// Receive a Notification or Data message into the given buffer. The buffer is cleared and grown
+// to at least `RECV_BUFFER_SIZE` if required, a buffer with a larger capacity is used as is.
+pub(crate) async fn sctp_recvmsg_into_internal(
+    fd: &AsyncFd<RawFd>,
+    recv_buffer: &mut Vec<u8>,
+) -> std::io::Result<ReceivedMessageInfo> {
     log::debug!("Receiving Message on the socket.");
 
+    loop {
+        let mut guard = fd.readable().await?;
+
+        match sctp_try_recvmsg_into_internal(fd, recv_buffer) {
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => guard.clear_ready(),
+            result => return result,
+        }
+    }
+}
+
+// Non-blocking receive of a Notification or Data message, returns an error of the kind
+// `WouldBlock` when there is nothing to receive. The buffer is handled as in
+// `sctp_recvmsg_into_internal`.
+fn sctp_try_recvmsg_into_internal(
+    fd: &AsyncFd<RawFd>,
+    recv_buffer: &mut Vec<u8>,
+) -> std::io::Result<ReceivedMessageInfo> {
+    recv_buffer.clear();
+    let recv_buffer_size = recv_buffer.capacity().max(RECV_BUFFER_SIZE);
+
     //
     // Safety: recvmsg_hdr is valid in the current scope.
     unsafe {
         let rawfd = *fd.get_ref();
 
-        let mut recv_buffer = vec![0_u8; 4096];
-        let msg_control_size = libc::CMSG_SPACE(
-            std::mem::size_of::<RcvInfo>() as u32 + std::mem::size_of::<NxtInfo>() as u32,
-        );
+        // Space for both the `SCTP_RCVINFO` and the `SCTP_NXTINFO` control messages, each padded.
+        let msg_control_size = libc::CMSG_SPACE(std::mem::size_of::<RcvInfo>() as u32)
+            + libc::CMSG_SPACE(std::mem::size_of::<NxtInfo>() as u32);
         let mut msg_control = vec![0u8; msg_control_size.try_into().unwrap()];
         let mut from_buffer = vec![0u8; 256];
 
-        loop {
-            let mut guard = fd.readable().await?;
+        recv_buffer.resize(recv_buffer_size, 0_u8);
+        let mut recv_iov = libc::iovec {
+            iov_base: recv_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            iov_len: recv_buffer.len(),
+        };
 
-            recv_buffer.fill(0_u8);
-            let mut recv_iov = libc::iovec {
-                iov_base: recv_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
-                iov_len: recv_buffer.len(),
-            };
+        #[cfg(target_os = "macos")]
+        let msg_controllen = msg_control_size as u32;
 
-            msg_control.fill(0);
-            from_buffer.fill(0);
-            #[cfg(target_os = "macos")]
-            let msg_controllen = msg_control_size as u32;
-
-            #[cfg(not(target_os = "macos"))]
-            let msg_controllen = msg_control_size as usize;
-
-            let mut recvmsg_header = libc::msghdr {
-                msg_name: from_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
-                msg_namelen: from_buffer.len() as u32,
-                msg_iov: &mut recv_iov,
-                msg_iovlen: 1,
-                msg_control: msg_control.as_mut_ptr() as *mut _ as *mut libc::c_void,
-                msg_controllen: msg_controllen,
-                msg_flags: 0,
-            };
+        #[cfg(not(target_os = "macos"))]
+        let msg_controllen = msg_control_size as usize;
 
-            let flags = 0 as libc::c_int;
-            let result = libc::recvmsg(rawfd, &mut recvmsg_header as *mut libc::msghdr, flags);
-            if result < 0 {
-                let last_error = std::io::Error::last_os_error();
-                if last_error.kind() == std::io::ErrorKind::WouldBlock {
-                    guard.clear_ready();
-                } else {
-                    return Err(last_error);
-                }
-            } else {
-                let received_flags: u32 = recvmsg_header.msg_flags.try_into().unwrap();
-                recv_buffer.truncate(result as usize);
-
-                if received_flags & MSG_NOTIFICATION != 0 {
-                    log::debug!("Received Notification.");
-                    return Ok(NotificationOrData::Notification(notification_from_message(
-                        &recv_buffer,
-                    )));
-                } else {
-                    let mut rcv_info = None;
-                    let mut nxt_info = None;
-                    let mut cmsghdr = libc::CMSG_FIRSTHDR(&mut recvmsg_header as *mut libc::msghdr);
-                    loop {
-                        if cmsghdr.is_null() {
-                            break;
-                        }
-                        if (*cmsghdr).cmsg_level != libc::IPPROTO_SCTP {
-                            log::warn!("cmsg_level is not SCTP");
-                            continue;
-                        }
+        let mut recvmsg_header = libc::msghdr {
+            msg_name: from_buffer.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            msg_namelen: from_buffer.len() as u32,
+            msg_iov: &mut recv_iov,
+            msg_iovlen: 1,
+            msg_control: msg_control.as_mut_ptr() as *mut _ as *mut libc::c_void,
+            msg_controllen,
+            msg_flags: 0,
+        };
 
-                        if (*cmsghdr).cmsg_type == CmsgType::RcvInfo as i32 {
-                            let mut recv_info_internal = RcvInfo::default();
-                            let cmsg_data = libc::CMSG_DATA(cmsghdr);
-                            std::ptr::copy(
-                                cmsg_data,
-                                &mut recv_info_internal as *mut _ as *mut u8,
-                                std::mem::size_of::<RcvInfo>(),
-                            );
-                            log::debug!("Received: RcvInfo: {:#?}", recv_info_internal);
-                            rcv_info = Some(recv_info_internal);
-                        }
+        let flags = 0 as libc::c_int;
+        let result = libc::recvmsg(rawfd, &mut recvmsg_header as *mut libc::msghdr, flags);
+        if result < 0 {
+            let last_error = std::io::Error::last_os_error();
+            recv_buffer.clear();
+            return Err(last_error);
+        }
 
-                        if (*cmsghdr).cmsg_type == CmsgType::NxtInfo as i32 {
-                            let mut nxt_info_internal = NxtInfo::default();
-                            let cmsg_data = libc::CMSG_DATA(cmsghdr);
-                            std::ptr::copy(
-                                cmsg_data,
-                                &mut nxt_info_internal as *mut _ as *mut u8,
-                                std::mem::size_of::<NxtInfo>(),
-                            );
-                            log::debug!("Received: NxtInfo: {:#?}", nxt_info_internal);
-                            nxt_info = Some(nxt_info_internal);
-                        }
+        let received_flags: u32 = recvmsg_header.msg_flags.try_into().unwrap();
+        let eor = received_flags & libc::MSG_EOR as u32 != 0;
+        recv_buffer.truncate(result as usize);
+
+        if received_flags & MSG_NOTIFICATION != 0 {
+            log::debug!("Received Notification.");
+            return Ok(ReceivedMessageInfo {
+                notification: true,
+                rcv_info: None,
+                nxt_info: None,
+                eor,
+            });
+        }
 
-                        cmsghdr = libc::CMSG_NXTHDR(
-                            msg_control.as_mut_ptr() as *mut _ as *mut libc::msghdr,
-                            cmsghdr,
-                        );
-                    }
-                    if recv_buffer.len() > 0 {
-                        log::debug!("Received Data.");
-                        return Ok(NotificationOrData::Data(ReceivedData {
-                            payload: recv_buffer,
-                            rcv_info,
-                            nxt_info,
-                        }));
-                    }
-                    return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Buffer empty"));
-                }
+        let mut rcv_info = None;
+        let mut nxt_info = None;
+        let mut cmsghdr = libc::CMSG_FIRSTHDR(&recvmsg_header);
+        while !cmsghdr.is_null() {
+            let cmsg_len = (*cmsghdr).cmsg_len as usize;
+            if (*cmsghdr).cmsg_level != libc::IPPROTO_SCTP {
+                log::warn!("cmsg_level is not SCTP");
+            } else if (*cmsghdr).cmsg_type == CmsgType::RcvInfo as i32
+                && cmsg_len >= libc::CMSG_LEN(std::mem::size_of::<RcvInfo>() as u32) as usize
+            {
+                let mut recv_info_internal = RcvInfo::default();
+                let cmsg_data = libc::CMSG_DATA(cmsghdr);
+                std::ptr::copy(
+                    cmsg_data,
+                    &mut recv_info_internal as *mut _ as *mut u8,
+                    std::mem::size_of::<RcvInfo>(),
+                );
+                log::debug!("Received: RcvInfo: {:#?}", recv_info_internal);
+                rcv_info = Some(recv_info_internal);
+            } else if (*cmsghdr).cmsg_type == CmsgType::NxtInfo as i32
+                && cmsg_len >= libc::CMSG_LEN(std::mem::size_of::<NxtInfo>() as u32) as usize
+            {
+                let mut nxt_info_internal = NxtInfo::default();
+                let cmsg_data = libc::CMSG_DATA(cmsghdr);
+                std::ptr::copy(
+                    cmsg_data,
+                    &mut nxt_info_internal as *mut _ as *mut u8,
+                    std::mem::size_of::<NxtInfo>(),
+                );
+                log::debug!("Received: NxtInfo: {:#?}", nxt_info_internal);
+                nxt_info = Some(nxt_info_internal);
             }
+
+            cmsghdr = libc::CMSG_NXTHDR(&recvmsg_header, cmsghdr);
+        }
+        if !recv_buffer.is_empty() {
+            log::debug!("Received Data.");
+            return Ok(ReceivedMessageInfo {
+                notification: false,
+                rcv_info,
+                nxt_info,
+                eor,
+            });
+        }
+        // SCTP messages are never empty, an empty read is the end of file (ie. the
+        // peer has closed the association).
+        Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            "Association closed by the peer.",
+        ))
+    }
+}
+
+// Send each of the payloads as a separate message, on the `streams` in turn. Stops at the first
+// error, the messages sent until then are not affected.
+pub(crate) async fn sctp_send_round_robin_internal(
+    fd: &AsyncFd<RawFd>,
+    payloads: &[Vec<u8>],
+    streams: &[u16],
+) -> std::io::Result<()> {
+    if streams.is_empty() && !payloads.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "No streams to send the messages on.",
+        ));
+    }
+
+    for (payload, sid) in payloads.iter().zip(streams.iter().cycle()) {
+        let data = SendData {
+            payload: payload.clone(),
+            snd_info: Some(SendInfo {
+                sid: *sid,
+                ..Default::default()
+            }),
+            auth_key_id: None,
+        };
+        sctp_sendmsg_internal(fd, None, data).await?;
+    }
+
+    Ok(())
+}
+
+// Send the payload split into the messages no larger than the fragmentation point of the
+// association, so that each message is sent in a single `DATA` chunk. Returns the number of the
+// messages sent, stops at the first error.
+pub(crate) async fn sctp_send_fitting_internal(
+    fd: &AsyncFd<RawFd>,
+    payload: &[u8],
+    sid: u16,
+) -> std::io::Result<usize> {
+    if payload.is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Zero length messages cannot be sent on SCTP.",
+        ));
+    }
+
+    let fragmentation_pt = sctp_get_status_internal(fd, 0.into())?.fragmentation_pt as usize;
+    if fragmentation_pt == 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "Fragmentation point of the association is not known.",
+        ));
+    }
+
+    let mut sent = 0;
+    for chunk in payload.chunks(fragmentation_pt) {
+        let data = SendData {
+            payload: chunk.to_vec(),
+            snd_info: Some(SendInfo {
+                sid,
+                ..Default::default()
+            }),
+            auth_key_id: None,
+        };
+        sctp_sendmsg_internal(fd, None, data).await?;
+        sent += 1;
+    }
+
+    Ok(sent)
+}
+
+// Receive on a socket with the given receive `state`: the events of a restarted association are
+// subscribed to again, the unsupported Notifications are handled as per the policy and the parts
+// of the Data messages are buffered until the last part of the message is received, as per the
+// `reassembly`.
+pub(crate) async fn sctp_recvmsg_reassembled_internal(
+    fd: &AsyncFd<RawFd>,
+    state: &ReceiveState,
+    reassembly: Reassembly,
+) -> std::io::Result<NotificationOrData> {
+    loop {
+        let (received, eor) = match state.pending.pop() {
+            Some((info, recv_buffer)) => pending_message(info, recv_buffer),
+            None => sctp_recvmsg_eor_internal(fd).await?,
+        };
+        if let Some(received) = received_with_state(fd, state, received, eor, reassembly)? {
+            return Ok(received);
+        }
+    }
+}
+
+// Non-blocking receive on a socket with the given receive `state`, see
+// `sctp_recvmsg_reassembled_internal`. Returns `None` when there is nothing (more) to receive.
+pub(crate) fn sctp_try_recvmsg_reassembled_internal(
+    fd: &AsyncFd<RawFd>,
+    state: &ReceiveState,
+) -> std::io::Result<Option<NotificationOrData>> {
+    loop {
+        let (received, eor) = match state.pending.pop() {
+            Some((info, recv_buffer)) => pending_message(info, recv_buffer),
+            None => match sctp_try_recvmsg_eor_internal(fd)? {
+                Some(received) => received,
+                None => return Ok(None),
+            },
+        };
+        if let Some(received) =
+            received_with_state(fd, state, received, eor, Reassembly::Configured)?
+        {
+            return Ok(Some(received));
         }
     }
 }
 
+// Build the Notification or Data from a message held in the receive state, along with whether it
+// is the end of the record.
+fn pending_message(info: ReceivedMessageInfo, recv_buffer: Vec<u8>) -> (NotificationOrData, bool) {
+    let eor = info.eor;
+    (received_message(info, recv_buffer), eor)
+}
+
+// Process a received Notification or (part of a) Data message as per the receive `state`.
+// Returns `None` if nothing is to be returned to the caller (yet).
+fn received_with_state(
+    fd: &AsyncFd<RawFd>,
+    state: &ReceiveState,
+    received: NotificationOrData,
+    eor: bool,
+    reassembly: Reassembly,
+) -> std::io::Result<Option<NotificationOrData>> {
+    if let NotificationOrData::Notification(notification) = &received {
+        resubscribe_on_restart_internal(fd, &state.resubscribe, notification);
+    }
+    let received = match state.policy.get().apply(received)? {
+        Some(received) => received,
+        None => return Ok(None),
+    };
+
+    match reassembly {
+        Reassembly::Configured if !state.partial.enabled() => Ok(Some(received)),
+        Reassembly::Bounded(max) => state.partial.received(received, eor, Some(max)),
+        _ => state.partial.received(received, eor, None),
+    }
+}
+
 // Implementation of the Send side for SCTP.
 pub(crate) async fn sctp_sendmsg_internal(
     fd: &AsyncFd<RawFd>,
     to: Option<SocketAddr>,
     data: SendData,
 ) -> std::io::Result<()> {
+    sctp_sendmsg_cmsg_internal(fd, to, &data.payload, data.snd_info, None, data.auth_key_id)
+        .await
+        .map(|_| ())
+}
+
+// Implementation of `sctp_sendmsg` (See Section 9.7 of RFC 6458), sending a message to the given
+// peer address using the `SCTP_SNDRCV` ancillary data, which is required for the time to live.
+// Returns the number of bytes sent.
+pub(crate) async fn sctp_sendmsg_to_internal(
+    fd: &AsyncFd<RawFd>,
+    payload: &[u8],
+    to: SocketAddr,
+    snd_rcv_info: SndRcvInfo,
+) -> std::io::Result<usize> {
+    check_address_family_internal(fd, &[to])?;
+
+    sctp_sendmsg_cmsg_internal(fd, Some(to), payload, None, Some(snd_rcv_info), None).await
+}
+
+// Send the `payload` with the given ancillary data: `SCTP_SNDINFO` and / or the deprecated
+// `SCTP_SNDRCV` (See Section 5.3 of RFC 6458) and `SCTP_AUTHINFO`. Returns the number of bytes
+// sent.
+async fn sctp_sendmsg_cmsg_internal(
+    fd: &AsyncFd<RawFd>,
+    to: Option<SocketAddr>,
+    payload: &[u8],
+    snd_info: Option<SendInfo>,
+    snd_rcv_info: Option<SndRcvInfo>,
+    auth_key_id: Option<u16>,
+) -> std::io::Result<usize> {
+    // An SCTP `DATA` chunk always carries at least one byte of user data (Section 3.3.1 RFC
+    // 4960), so there is no way to send an empty message. The only exceptions are the `SCTP_EOF`
+    // and `SCTP_ABORT` sends, which do not send any `DATA` chunk. Return a clear error here,
+    // rather than the `EINVAL` from the kernel.
+    let flags = snd_info.as_ref().map_or(0, |info| info.flags)
+        | snd_rcv_info.as_ref().map_or(0, |info| info.flags);
+    if payload.is_empty() && flags & (SCTP_EOF | SCTP_ABORT) == 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Zero length messages cannot be sent on SCTP, use heartbeats for keepalives.",
+        ));
+    }
+
+    // The address is kept in the function scope, so that it outlives the `sendmsg` call below.
+    let os_sockaddr: Option<OsSocketAddr> = to.map(Into::into);
+
     // Safety: All the pointers are valid because they are within the current scope.
     // Also, this is just a wrapper over `libc` call.
     unsafe {
         let _ = fd.writable().await?;
 
         let mut send_iov = libc::iovec {
-            iov_base: data.payload.as_ptr() as *mut libc::c_void,
-            iov_len: data.payload.len(),
+            iov_base: payload.as_ptr() as *mut libc::c_void,
+            iov_len: payload.len(),
         };
 
-        let (to_buffer, to_buffer_len) = if let Some(addr) = to {
-            let os_sockaddr: OsSocketAddr = addr.into();
+        let (to_buffer, to_buffer_len) = if let Some(os_sockaddr) = os_sockaddr.as_ref() {
             (
                 os_sockaddr.as_ptr() as *mut libc::c_void,
                 os_sockaddr.capacity(),
@@ -598,27 +1267,33 @@ pub(crate) async fn sctp_sendmsg_internal(
             (std::ptr::null::<OsSocketAddr>() as *mut libc::c_void, 0)
         };
         // TODO: Support copy and other send info as well.
-        let msg_control_size = libc::CMSG_SPACE(std::mem::size_of::<SendInfo>() as u32);
-        let mut msg_control_buffer = vec![0u8; msg_control_size.try_into().unwrap()];
-
-        let (msg_control, msg_control_size) = if data.snd_info.is_some() {
-            // Safety: wrapper over `libc` call. the size of the structures are wellknown.
+        let snd_info_space = libc::CMSG_SPACE(std::mem::size_of::<SendInfo>() as u32) as usize;
+        let snd_rcv_info_space =
+            libc::CMSG_SPACE(std::mem::size_of::<SndRcvInfo>() as u32) as usize;
+        let auth_info_space = libc::CMSG_SPACE(std::mem::size_of::<u16>() as u32) as usize;
+
+        let mut msg_control_size = 0_usize;
+        if snd_info.is_some() {
+            msg_control_size += snd_info_space;
+        }
+        if snd_rcv_info.is_some() {
+            msg_control_size += snd_rcv_info_space;
+        }
+        if auth_key_id.is_some() {
+            msg_control_size += auth_info_space;
+        }
+        let mut msg_control_buffer = vec![0u8; msg_control_size];
 
-            (
-                msg_control_buffer.as_mut_ptr() as *mut libc::c_void,
-                msg_control_size as usize,
-            )
+        let msg_control = if msg_control_size > 0 {
+            msg_control_buffer.as_mut_ptr() as *mut libc::c_void
         } else {
-            (
-                std::ptr::null::<libc::cmsghdr>() as *mut libc::c_void,
-                0_usize,
-            )
+            std::ptr::null::<libc::cmsghdr>() as *mut libc::c_void
         };
         #[cfg(target_os = "macos")]
         let msg_controllen = msg_control_size as u32;
 
         #[cfg(not(target_os = "macos"))]
-        let msg_controllen = msg_control_size as usize;
+        let msg_controllen = msg_control_size;
 
         let mut sendmsg_header = libc::msghdr {
             msg_name: to_buffer,
@@ -630,8 +1305,8 @@ pub(crate) async fn sctp_sendmsg_internal(
             msg_flags: 0,
         };
 
-        let cmsg_hdr = libc::CMSG_FIRSTHDR(&sendmsg_header);
-        if !cmsg_hdr.is_null() {
+        let mut cmsg_hdr = libc::CMSG_FIRSTHDR(&sendmsg_header);
+        if let Some(snd_info) = snd_info {
             (*cmsg_hdr).cmsg_level = libc::IPPROTO_SCTP;
             (*cmsg_hdr).cmsg_type = CmsgType::SndInfo as i32;
             (*cmsg_hdr).cmsg_len =
@@ -639,12 +1314,44 @@ pub(crate) async fn sctp_sendmsg_internal(
                     .try_into()
                     .unwrap();
 
-            let snd_info = data.snd_info.unwrap();
             std::ptr::copy(
                 std::ptr::addr_of!(snd_info) as *const _,
-                libc::CMSG_DATA(cmsg_hdr) as *mut _ as *mut u8,
+                libc::CMSG_DATA(cmsg_hdr),
                 std::mem::size_of::<SendInfo>(),
             );
+            cmsg_hdr = libc::CMSG_NXTHDR(&sendmsg_header, cmsg_hdr);
+        }
+
+        if let Some(snd_rcv_info) = snd_rcv_info {
+            (*cmsg_hdr).cmsg_level = libc::IPPROTO_SCTP;
+            (*cmsg_hdr).cmsg_type = CmsgType::SndRcv as i32;
+            (*cmsg_hdr).cmsg_len =
+                libc::CMSG_LEN(std::mem::size_of::<SndRcvInfo>().try_into().unwrap())
+                    .try_into()
+                    .unwrap();
+
+            std::ptr::copy(
+                std::ptr::addr_of!(snd_rcv_info) as *const _,
+                libc::CMSG_DATA(cmsg_hdr),
+                std::mem::size_of::<SndRcvInfo>(),
+            );
+            cmsg_hdr = libc::CMSG_NXTHDR(&sendmsg_header, cmsg_hdr);
+        }
+
+        // `SCTP_AUTHINFO`: Key number of the shared key used to authenticate the message. See
+        // Section 5.3.8 of RFC 6458.
+        if let Some(key_id) = auth_key_id {
+            (*cmsg_hdr).cmsg_level = libc::IPPROTO_SCTP;
+            (*cmsg_hdr).cmsg_type = CmsgType::AuthInfo as i32;
+            (*cmsg_hdr).cmsg_len = libc::CMSG_LEN(std::mem::size_of::<u16>().try_into().unwrap())
+                .try_into()
+                .unwrap();
+
+            std::ptr::copy(
+                std::ptr::addr_of!(key_id) as *const _,
+                libc::CMSG_DATA(cmsg_hdr),
+                std::mem::size_of::<u16>(),
+            );
         }
 
         let rawfd = *fd.get_ref();
@@ -652,6 +1359,196 @@ pub(crate) async fn sctp_sendmsg_internal(
         let flags = 0 as libc::c_int;
 
         let result = libc::sendmsg(rawfd, &mut sendmsg_header as *mut libc::msghdr, flags);
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(result as usize)
+        }
+    }
+}
+
+// Send on a socket that tracks in `blocked` whether the send failed because it would have blocked
+// (`EAGAIN`).
+pub(crate) async fn sctp_send_blocked_internal(
+    fd: &AsyncFd<RawFd>,
+    blocked: &SendBlocked,
+    data: SendData,
+) -> std::io::Result<()> {
+    let result = sctp_sendmsg_internal(fd, None, data).await;
+
+    let would_block = matches!(&result, Err(e) if e.kind() == std::io::ErrorKind::WouldBlock);
+    blocked.set(would_block);
+
+    result
+}
+
+// Send an ordered message and return the Stream Sequence Number assigned to it, which is tracked
+// in `ssns` since the kernel does not report it back. The association is looked up before the
+// send, so that a failed lookup does not fail a message that was sent. Without a `SendInfo` the
+// message is sent with the default send parameters of the socket, so the stream and the flags are
+// taken from those.
+pub(crate) async fn sctp_send_tracked_internal(
+    fd: &AsyncFd<RawFd>,
+    ssns: &SentSsns,
+    data: SendData,
+) -> std::io::Result<u16> {
+    let (sid, flags) = match data.snd_info.as_ref() {
+        Some(info) => (info.sid, info.flags),
+        None => {
+            let info = sctp_get_default_sendinfo_internal(fd, 0.into())?;
+            (info.sid, info.flags)
+        }
+    };
+    if flags & SCTP_UNORDERED != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Unordered messages are not assigned a Stream Sequence Number.",
+        ));
+    }
+
+    let assoc_id = match data.snd_info.as_ref().map(|info| info.assoc_id) {
+        Some(assoc_id) if !assoc_id.is_sentinel() => assoc_id,
+        _ => sctp_get_status_internal(fd, 0.into())?.assoc_id,
+    };
+
+    sctp_sendmsg_internal(fd, None, data).await?;
+
+    Ok(ssns.next(assoc_id, sid))
+}
+
+// Get the Association ID of the association with the given peer address using
+// `SCTP_GET_PEER_ADDR_INFO`. The kernel looks up the association by the address, when the
+// Association ID is not specified.
+pub(crate) fn sctp_peer_addr_assoc_id_internal(
+    fd: &AsyncFd<RawFd>,
+    addr: SocketAddr,
+) -> std::io::Result<AssociationId> {
+    // Safety: `PeerAddrInfoInternal` is a C structure that is valid when zeroed.
+    let mut peer_addr_info: PeerAddrInfoInternal = unsafe { std::mem::zeroed() };
+    peer_addr_info.address = sockaddr_storage_from(Some(addr));
+    sctp_getsockopt_internal(fd, SCTP_GET_PEER_ADDR_INFO, &mut peer_addr_info)?;
+
+    Ok(peer_addr_info.assoc_id)
+}
+
+// Get the smallest Path MTU of all the peer addresses of the association, using
+// `SCTP_GET_PEER_ADDR_INFO` for each of the peer addresses.
+pub(crate) fn sctp_association_pmtu_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    let mut pmtu = None;
+    for addr in sctp_getpaddrs_internal(fd, assoc_id)? {
+        // Safety: `PeerAddrInfoInternal` is a C structure that is valid when zeroed.
+        let mut peer_addr_info: PeerAddrInfoInternal = unsafe { std::mem::zeroed() };
+        peer_addr_info.assoc_id = assoc_id;
+        peer_addr_info.address = sockaddr_storage_from(Some(addr));
+        sctp_getsockopt_internal(fd, SCTP_GET_PEER_ADDR_INFO, &mut peer_addr_info)?;
+
+        let mtu = peer_addr_info.mtu;
+        log::debug!("Path MTU of {}: {}", addr, mtu);
+        pmtu = Some(pmtu.map_or(mtu, |pmtu: u32| pmtu.min(mtu)));
+    }
+
+    pmtu.ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "No peer addresses for the association.",
+        )
+    })
+}
+
+// Send the data and wait for the `SCTP_SENDER_DRY_EVENT` notification, that is delivered when
+// there is no user data outstanding on the association, ie. all the data sent so far (including
+// this message) is acknowledged by the peer. See Section 6.1.9 of RFC 6458.
+//
+// The event is subscribed only after sending the data, the kernel delivers the event right away
+// on subscribing, if all the data is already acknowledged by then. Any other data or notification
+// received while waiting for the event is held in the receive `state`, to be returned by the next
+// receive calls. The subscription to the event is left as it was before the call, the event is
+// also held in the `state` if it was already subscribed to.
+//
+// When the event was already subscribed to, a stale event from an earlier send may still be
+// queued on the socket. Hence every event is confirmed using `SCTP_STATUS`, there should be no
+// unacknowledged or pending data, else the wait goes on for the next event.
+pub(crate) async fn sctp_send_confirmed_internal(
+    fd: &AsyncFd<RawFd>,
+    state: &ReceiveState,
+    data: SendData,
+    timeout: std::time::Duration,
+) -> std::io::Result<bool> {
+    sctp_sendmsg_internal(fd, None, data).await?;
+
+    let subscribed =
+        sctp_get_event_subscribed_internal(fd, Event::SenderDry, SubscribeEventAssocId::Current)?;
+    if !subscribed {
+        sctp_subscribe_event_internal(fd, Event::SenderDry, SubscribeEventAssocId::Current, true)?;
+    }
+
+    let wait_for_dry = async {
+        loop {
+            let mut recv_buffer = vec![];
+            let info = sctp_recvmsg_into_internal(fd, &mut recv_buffer).await?;
+            let dry = info.notification
+                && matches!(
+                    notification_from_message(&recv_buffer),
+                    Notification::SenderDryEvent
+                );
+            if !dry || subscribed {
+                state.pending.push(info, recv_buffer);
+            }
+            if dry {
+                let status = sctp_get_status_internal(fd, 0.into())?;
+                if status.unacked_data == 0 && status.pending_data == 0 {
+                    return Ok::<(), std::io::Error>(());
+                }
+            }
+        }
+    };
+    let result = tokio::time::timeout(timeout, wait_for_dry).await;
+
+    if !subscribed {
+        sctp_subscribe_event_internal(fd, Event::SenderDry, SubscribeEventAssocId::Current, false)?;
+    }
+
+    match result {
+        Ok(result) => result.map(|_| true),
+        Err(_) => Ok(false),
+    }
+}
+
+// Set a shared key for the association. See Section 8.3.2 of RFC 6458.
+//
+// `struct sctp_authkey` is a variable length structure with the key appended to it, hence the
+// option value is built into a byte buffer here.
+pub(crate) fn sctp_set_shared_key_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    key_id: u16,
+    key: &[u8],
+) -> std::io::Result<()> {
+    let key_len: u16 = key.len().try_into().map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Shared key cannot be longer than 65535 bytes.",
+        )
+    })?;
+
+    let mut authkey: Vec<u8> = vec![];
+    authkey.extend(assoc_id.as_i32().to_ne_bytes());
+    authkey.extend(key_id.to_ne_bytes());
+    authkey.extend(key_len.to_ne_bytes());
+    authkey.extend(key);
+
+    // Safety: `authkey` is valid for the entire scope of the function.
+    unsafe {
+        let result = libc::setsockopt(
+            *fd.get_ref(),
+            SOL_SCTP,
+            SCTP_AUTH_KEY,
+            authkey.as_ptr() as *const libc::c_void,
+            authkey.len() as libc::socklen_t,
+        );
         if result < 0 {
             Err(std::io::Error::last_os_error())
         } else {
@@ -660,6 +1557,79 @@ pub(crate) async fn sctp_sendmsg_internal(
     }
 }
 
+// Make the shared key with the given key number the active key. See Section 8.3.3 of RFC 6458.
+pub(crate) fn sctp_activate_key_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    key_id: u16,
+) -> std::io::Result<()> {
+    let auth_key_id = AuthKeyId { assoc_id, key_id };
+    sctp_setsockopt_internal(fd, SCTP_AUTH_ACTIVE_KEY, &auth_key_id)
+}
+
+// Get the active shared key for the association. See Section 8.3.3 of RFC 6458.
+pub(crate) fn sctp_get_active_key_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<u16> {
+    let mut auth_key_id = AuthKeyId {
+        assoc_id,
+        key_id: 0,
+    };
+    sctp_getsockopt_internal(fd, SCTP_AUTH_ACTIVE_KEY, &mut auth_key_id)?;
+
+    Ok(auth_key_id.key_id)
+}
+
+// Get the active and the installed shared keys for the association. The installed keys are not
+// available from the kernel, but are tracked as they are installed.
+pub(crate) fn sctp_auth_key_state_internal(
+    fd: &AsyncFd<RawFd>,
+    auth_keys: &AuthKeys,
+    assoc_id: AssociationId,
+) -> std::io::Result<AuthKeyState> {
+    Ok(AuthKeyState {
+        active: sctp_get_active_key_internal(fd, assoc_id)?,
+        installed: auth_keys.installed(assoc_id),
+    })
+}
+
+// Add a chunk type that the peer must send authenticated. See Section 8.3.1 of RFC 6458.
+pub(crate) fn sctp_set_auth_chunk_internal(
+    fd: &AsyncFd<RawFd>,
+    chunk_type: u8,
+) -> std::io::Result<()> {
+    sctp_setsockopt_internal(fd, SCTP_AUTH_CHUNK, &chunk_type)
+}
+
+// Send a message with `SCTP_ABORT` or `SCTP_EOF` flags set for the given association. See Section
+// 5.3.4 of RFC 6458. For `SCTP_ABORT` the payload is the user initiated abort reason and for
+// `SCTP_EOF` the payload should be empty.
+pub(crate) async fn sctp_send_flags_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    flags: u16,
+    payload: &[u8],
+) -> std::io::Result<()> {
+    log::debug!(
+        "Sending message with flags: {:x} for Association ID: {}",
+        flags,
+        assoc_id
+    );
+
+    let data = SendData {
+        payload: payload.to_vec(),
+        snd_info: Some(SendInfo {
+            flags,
+            assoc_id,
+            ..Default::default()
+        }),
+        auth_key_id: None,
+    };
+
+    sctp_sendmsg_internal(fd, None, data).await
+}
+
 pub(crate) fn sctp_set_default_sendinfo_internal(
     fd: &AsyncFd<RawFd>,
     sendinfo: SendInfo,
@@ -680,8 +1650,80 @@ pub(crate) fn sctp_set_default_sendinfo_internal(
     }
 }
 
-fn notification_from_message(data: &[u8]) -> Notification {
+// Get the default `SendInfo` for the given Association ID.
+pub(crate) fn sctp_get_default_sendinfo_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<SendInfo> {
+    log::debug!("Getting default `SendInfo` using `getsockopt`.");
+
+    let mut sendinfo = SendInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    let mut sendinfo_size = std::mem::size_of::<SendInfo>() as libc::socklen_t;
+
+    // Safety: `sendinfo` and `sendinfo_size` are valid in the current scope.
+    unsafe {
+        let result = libc::getsockopt(
+            *fd.get_ref(),
+            SOL_SCTP,
+            SCTP_DEFAULT_SNDINFO,
+            &mut sendinfo as *mut _ as *mut libc::c_void,
+            &mut sendinfo_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(sendinfo)
+        }
+    }
+}
+
+// Minimum sizes of the notification structures (See Section 6.1 of RFC 6458). The notifications
+// shorter than these are not parsed.
+const SCTP_NOTIFICATION_HEADER_SIZE: usize = 8;
+const SCTP_ASSOC_CHANGE_SIZE: usize = 20;
+const SCTP_SHUTDOWN_EVENT_SIZE: usize = 12;
+const SCTP_ADAPTATION_EVENT_SIZE: usize = 16;
+const SCTP_PDAPI_EVENT_SIZE: usize = 24;
+const SCTP_SEND_FAILED_SIZE: usize = 48;
+const SCTP_SEND_FAILED_EVENT_SIZE: usize = 32;
+const SCTP_PADDR_CHANGE_SIZE: usize = 148;
+
+pub(crate) fn notification_from_message(data: &[u8]) -> Notification {
+    if data.len() < SCTP_NOTIFICATION_HEADER_SIZE {
+        log::error!("Notification too short: {} bytes.", data.len());
+        return Notification::Unsupported;
+    }
+
     let notification_type = u16::from_ne_bytes(data[0..2].try_into().unwrap());
+
+    // The variable length tails of the notifications are bound to the `length` reported in the
+    // header as well as to the received buffer.
+    let length = u32::from_ne_bytes(data[4..8].try_into().unwrap()) as usize;
+    let data = &data[..length.min(data.len())];
+
+    let min_size = match notification_type {
+        SCTP_ASSOC_CHANGE => SCTP_ASSOC_CHANGE_SIZE,
+        SCTP_SHUTDOWN => SCTP_SHUTDOWN_EVENT_SIZE,
+        SCTP_ADAPTATION_INDICATION => SCTP_ADAPTATION_EVENT_SIZE,
+        SCTP_PARTIAL_DELIVERY_EVENT => SCTP_PDAPI_EVENT_SIZE,
+        SCTP_SEND_FAILED => SCTP_SEND_FAILED_SIZE,
+        SCTP_SEND_FAILED_EVENT => SCTP_SEND_FAILED_EVENT_SIZE,
+        SCTP_PEER_ADDR_CHANGE => SCTP_PADDR_CHANGE_SIZE,
+        _ => SCTP_NOTIFICATION_HEADER_SIZE,
+    };
+    if data.len() < min_size {
+        log::error!(
+            "Notification {:x} too short: {} bytes, expected at least {} bytes.",
+            notification_type,
+            data.len(),
+            min_size
+        );
+        return Notification::Unsupported;
+    }
+
     log::trace!(
         "notification_type: {:x}, SCTP_ASSOC_CHANGE: {:x}",
         notification_type,
@@ -700,7 +1742,7 @@ fn notification_from_message(data: &[u8]) -> Notification {
                 error: u16::from_ne_bytes(data[10..12].try_into().unwrap()),
                 ob_streams: u16::from_ne_bytes(data[12..14].try_into().unwrap()),
                 ib_streams: u16::from_ne_bytes(data[14..16].try_into().unwrap()),
-                assoc_id: i32::from_ne_bytes(data[16..20].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[16..20].try_into().unwrap()).into(),
                 info: data[20..].into(),
             };
             Notification::AssociationChange(assoc_change)
@@ -711,19 +1753,85 @@ fn notification_from_message(data: &[u8]) -> Notification {
                 ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
                 flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
                 length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
-                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[8..12].try_into().unwrap()).into(),
             };
             Notification::Shutdown(shutdown)
         }
         SCTP_PEER_ADDR_CHANGE => {
             log::debug!("SCTP_PEER_ADDR_CHANGE Notification Received.");
-            // TODO: handle notification
-            Notification::PeerAddrChange
+            // Safety: `data` is at least `SCTP_PADDR_CHANGE_SIZE` long, so valid for the size of
+            // `sockaddr_storage` starting at offset 8.
+            let address = unsafe {
+                OsSocketAddr::copy_from_raw(
+                    data[8..136].as_ptr() as *const libc::sockaddr,
+                    std::mem::size_of::<libc::sockaddr_storage>()
+                        .try_into()
+                        .unwrap(),
+                )
+            }
+            .into_addr();
+            match address {
+                Some(address) => {
+                    let peer_addr_change = PeerAddrChange {
+                        ev_type: Event::from_u16(u16::from_ne_bytes(
+                            data[0..2].try_into().unwrap(),
+                        )),
+                        flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                        length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                        address,
+                        state: PeerAddrState::from_i32(i32::from_ne_bytes(
+                            data[136..140].try_into().unwrap(),
+                        )),
+                        error: i32::from_ne_bytes(data[140..144].try_into().unwrap()),
+                        assoc_id: i32::from_ne_bytes(data[144..148].try_into().unwrap()).into(),
+                    };
+                    Notification::PeerAddrChange(peer_addr_change)
+                }
+                None => {
+                    log::error!("Unsupported address family in SCTP_PEER_ADDR_CHANGE.");
+                    Notification::Unsupported
+                }
+            }
         }
         SCTP_SEND_FAILED => {
             log::debug!("SCTP_SEND_FAILED Notification Received.");
-            // TODO: handle notification
-            Notification::SendFailed
+            // `ssf_info` is a `sctp_sndrcvinfo`, which has a padding after the `sinfo_flags`.
+            let send_failed = SendFailed {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                error: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                info: SendInfo {
+                    sid: u16::from_ne_bytes(data[12..14].try_into().unwrap()),
+                    flags: u16::from_ne_bytes(data[16..18].try_into().unwrap()),
+                    ppid: u32::from_ne_bytes(data[20..24].try_into().unwrap()),
+                    context: u32::from_ne_bytes(data[24..28].try_into().unwrap()),
+                    assoc_id: i32::from_ne_bytes(data[40..44].try_into().unwrap()).into(),
+                },
+                assoc_id: i32::from_ne_bytes(data[44..48].try_into().unwrap()).into(),
+                data: data[SCTP_SEND_FAILED_SIZE..].into(),
+            };
+            Notification::SendFailed(send_failed)
+        }
+        SCTP_SEND_FAILED_EVENT => {
+            log::debug!("SCTP_SEND_FAILED_EVENT Notification Received.");
+            // `ssfe_info` is a `sctp_sndinfo`, the same as `SendInfo`.
+            let send_failed = SendFailed {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                error: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                info: SendInfo {
+                    sid: u16::from_ne_bytes(data[12..14].try_into().unwrap()),
+                    flags: u16::from_ne_bytes(data[14..16].try_into().unwrap()),
+                    ppid: u32::from_ne_bytes(data[16..20].try_into().unwrap()),
+                    context: u32::from_ne_bytes(data[20..24].try_into().unwrap()),
+                    assoc_id: i32::from_ne_bytes(data[24..28].try_into().unwrap()).into(),
+                },
+                assoc_id: i32::from_ne_bytes(data[28..32].try_into().unwrap()).into(),
+                data: data[SCTP_SEND_FAILED_EVENT_SIZE..].into(),
+            };
+            Notification::SendFailed(send_failed)
         }
         SCTP_REMOTE_ERROR => {
             log::debug!("SCTP_REMOTE_ERROR Notification Received.");
@@ -732,13 +1840,27 @@ fn notification_from_message(data: &[u8]) -> Notification {
         }
         SCTP_PARTIAL_DELIVERY_EVENT => {
             log::debug!("SCTP_PARTIAL_DELIVERY_EVENT Notification Received.");
-            // TODO: handle notification
-            Notification::PartialDeliveryEvent
+            let pdapi = PdapiEvent {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                indication: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[12..16].try_into().unwrap()).into(),
+                stream: u32::from_ne_bytes(data[16..20].try_into().unwrap()),
+                seq: u32::from_ne_bytes(data[20..24].try_into().unwrap()),
+            };
+            Notification::PartialDeliveryEvent(pdapi)
         }
         SCTP_ADAPTATION_INDICATION => {
             log::debug!("SCTP_ADAPTATION_INDICATION Notification Received.");
-            // TODO: handle notification
-            Notification::AdaptationIndication
+            let adaptation = AdaptationEvent {
+                ev_type: Event::from_u16(u16::from_ne_bytes(data[0..2].try_into().unwrap())),
+                flags: u16::from_ne_bytes(data[2..4].try_into().unwrap()),
+                length: u32::from_ne_bytes(data[4..8].try_into().unwrap()),
+                adaptation_ind: u32::from_ne_bytes(data[8..12].try_into().unwrap()),
+                assoc_id: i32::from_ne_bytes(data[12..16].try_into().unwrap()).into(),
+            };
+            Notification::AdaptationIndication(adaptation)
         }
         SCTP_AUTHENTICATION_EVENT => {
             log::debug!("SCTP_AUTHENTICATION_EVENT Notification Received.");
@@ -765,7 +1887,7 @@ pub(crate) fn sctp_subscribe_event_internal(
     on: bool,
 ) -> std::io::Result<()> {
     let subscriber = SubscribeEvent {
-        event,
+        event: event.as_u16(),
         assoc_id: assoc_id.into(),
         on,
     };
@@ -786,6 +1908,39 @@ pub(crate) fn sctp_subscribe_event_internal(
     }
 }
 
+// Get whether the event is subscribed to for the given association.
+pub(crate) fn sctp_get_event_subscribed_internal(
+    fd: &AsyncFd<RawFd>,
+    event: Event,
+    assoc_id: SubscribeEventAssocId,
+) -> std::io::Result<bool> {
+    let mut subscriber = SubscribeEvent {
+        event: event.as_u16(),
+        assoc_id: assoc_id.into(),
+        on: false,
+    };
+    sctp_getsockopt_internal(fd, SCTP_EVENT, &mut subscriber)?;
+
+    Ok(subscriber.on)
+}
+
+// Subscribe to all the events that are delivered as notifications. Stops at the first failure.
+pub(crate) fn sctp_enable_all_events_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: SubscribeEventAssocId,
+) -> std::io::Result<()> {
+    log::debug!("Subscribing to all notification events.");
+
+    for ev in NOTIFICATION_EVENTS.iter() {
+        sctp_subscribe_event_internal(fd, ev.clone(), assoc_id, true).map_err(|e| {
+            log::error!("Error: {} while subscribing to {:?}.", e, ev);
+            e
+        })?;
+    }
+
+    Ok(())
+}
+
 // Setup initiation parameters
 pub(crate) fn sctp_setup_init_params_internal(
     fd: &AsyncFd<RawFd>,
@@ -795,6 +1950,14 @@ pub(crate) fn sctp_setup_init_params_internal(
     timeout: u16,
 ) -> std::io::Result<()> {
     log::debug!("Setting up `init_params` using `setsockopt`");
+
+    // The initialization parameters are used only while setting up new associations, a One to
+    // One socket that is already connected (ie. has an association with the ID `0`) would never
+    // use them.
+    if sctp_get_status_internal(fd, 0.into()).is_ok() {
+        log::error!("Setting up `init_params` on a connected One to One socket.");
+        return Err(SctpError::InitMsgAfterConnect.into());
+    }
     let init_params = InitMsg {
         ostreams,
         istreams,
@@ -818,6 +1981,167 @@ pub(crate) fn sctp_setup_init_params_internal(
     }
 }
 
+// The error of setting the socket `option`, that names the option. The OS error is kept in the
+// `SctpError::SocketOption`, the other errors are returned as they are.
+fn option_failed(option: &'static str, e: std::io::Error) -> std::io::Error {
+    match e.raw_os_error() {
+        Some(errno) => SctpError::SocketOption { option, errno }.into(),
+        None => e,
+    }
+}
+
+// Apply the handshake parameters, first the `SCTP_INITMSG` and then the `SCTP_ASSOCINFO`. A value of
+// `0` means 'no change' for both the options, so only the parameters set are changed. The error
+// returned names the option that failed.
+pub(crate) fn sctp_set_handshake_params_internal(
+    fd: &AsyncFd<RawFd>,
+    params: &HandshakeParams,
+) -> std::io::Result<()> {
+    if params.max_init_attempts.is_some() || params.max_init_timeout.is_some() {
+        sctp_setup_init_params_internal(
+            fd,
+            0,
+            0,
+            params.max_init_attempts.unwrap_or_default(),
+            params.max_init_timeout.unwrap_or_default(),
+        )
+        .map_err(|e| option_failed("SCTP_INITMSG", e))?;
+    }
+
+    if let Some(cookie_life) = params.cookie_life {
+        let assoc_info = AssocInfo {
+            cookie_life,
+            ..Default::default()
+        };
+        sctp_set_assoc_info_internal(fd, assoc_info)
+            .map_err(|e| option_failed("SCTP_ASSOCINFO", e))?;
+    }
+
+    Ok(())
+}
+
+// Get whether the Nagle like algorithm is disabled (`SCTP_NODELAY`).
+pub(crate) fn sctp_get_nodelay_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<bool> {
+    log::debug!("Getting `SCTP_NODELAY` using `getsockopt`.");
+
+    let mut on: libc::c_int = 0;
+    sctp_getsockopt_internal(fd, SCTP_NODELAY, &mut on)?;
+
+    Ok(on != 0)
+}
+
+// Disable (or enable) the Nagle like algorithm (`SCTP_NODELAY`).
+pub(crate) fn sctp_set_nodelay_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io::Result<()> {
+    log::debug!("Setting `SCTP_NODELAY`: {} using `setsockopt`.", on);
+
+    let on = on as libc::c_int;
+    sctp_setsockopt_internal(fd, SCTP_NODELAY, &on)
+}
+
+// Get the default PR-SCTP time to live of the messages, `None` if the default policy is not the
+// time to live policy.
+pub(crate) fn sctp_get_default_pr_ttl_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<Option<std::time::Duration>> {
+    log::debug!("Getting `SCTP_DEFAULT_PRINFO` using `getsockopt`.");
+
+    let mut pr_info = DefaultPrInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    sctp_getsockopt_internal(fd, SCTP_DEFAULT_PRINFO, &mut pr_info)?;
+
+    if pr_info.policy == SCTP_PR_SCTP_TTL {
+        Ok(Some(std::time::Duration::from_millis(pr_info.value.into())))
+    } else {
+        Ok(None)
+    }
+}
+
+// Set the default PR-SCTP time to live of the messages of all the (current and future)
+// associations, `None` makes the messages fully reliable.
+pub(crate) fn sctp_set_default_pr_ttl_internal(
+    fd: &AsyncFd<RawFd>,
+    ttl: Option<std::time::Duration>,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting `SCTP_DEFAULT_PRINFO` TTL: {:?} using `setsockopt`.",
+        ttl
+    );
+
+    let pr_info = match ttl {
+        Some(ttl) => {
+            let value: u32 = ttl
+                .as_millis()
+                .try_into()
+                .ok()
+                .filter(|value| *value > 0)
+                .ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        "Time to live must be between 1 and `u32::MAX` milliseconds.",
+                    )
+                })?;
+            DefaultPrInfo {
+                assoc_id: SubscribeEventAssocId::All.into(),
+                value,
+                policy: SCTP_PR_SCTP_TTL,
+            }
+        }
+        None => DefaultPrInfo {
+            assoc_id: SubscribeEventAssocId::All.into(),
+            value: 0,
+            policy: SCTP_PR_SCTP_NONE,
+        },
+    };
+    sctp_setsockopt_internal(fd, SCTP_DEFAULT_PRINFO, &pr_info)
+}
+
+// Configure the socket for a bounded latency: the messages are abandoned after `max_latency`
+// (PR-SCTP time to live), the priority stream scheduler is used, the messages are sent right away
+// and the SACKs are delayed only for `BOUNDED_LATENCY_SACK_DELAY`.
+pub(crate) fn sctp_configure_bounded_latency_internal(
+    fd: &AsyncFd<RawFd>,
+    max_latency: std::time::Duration,
+) -> std::io::Result<()> {
+    sctp_set_default_pr_ttl_internal(fd, Some(max_latency))
+        .map_err(|e| option_failed("SCTP_DEFAULT_PRINFO", e))?;
+
+    log::debug!("Setting `SCTP_STREAM_SCHEDULER`: priority using `setsockopt`.");
+    sctp_set_assoc_value_internal(
+        fd,
+        SCTP_STREAM_SCHEDULER,
+        SubscribeEventAssocId::All.into(),
+        SCTP_SS_PRIO,
+    )
+    .map_err(|e| option_failed("SCTP_STREAM_SCHEDULER", e))?;
+
+    sctp_set_nodelay_internal(fd, true).map_err(|e| option_failed("SCTP_NODELAY", e))?;
+
+    log::debug!(
+        "Setting `SCTP_DELAYED_SACK`: {} ms using `setsockopt`.",
+        BOUNDED_LATENCY_SACK_DELAY
+    );
+    let sack_info = SackInfo {
+        assoc_id: SubscribeEventAssocId::All.into(),
+        sack_delay: BOUNDED_LATENCY_SACK_DELAY,
+        sack_freq: 0,
+    };
+    sctp_setsockopt_internal(fd, SCTP_DELAYED_SACK, &sack_info)
+        .map_err(|e| option_failed("SCTP_DELAYED_SACK", e))
+}
+
+// Get the initiation parameters used for the new associations.
+pub(crate) fn sctp_get_init_params_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<InitMsg> {
+    log::debug!("Getting `init_params` using `getsockopt`");
+
+    let mut init_params = InitMsg::default();
+    sctp_getsockopt_internal(fd, SCTP_INITMSG, &mut init_params)?;
+
+    Ok(init_params)
+}
+
 // Enable/Disable reception of `RcvInfo` actual call.
 pub(crate) fn request_rcvinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io::Result<()> {
     log::debug!("Requesting `rcv_info` along with received data on the socket.");
@@ -866,6 +2190,22 @@ pub(crate) fn request_nxtinfo_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io
     }
 }
 
+// Get the state of the association. `SCTP_STATUS` fails with `EINVAL` for an association that is
+// already closed, as well as for an unknown Assoc ID. Only on a One to One socket, that has the
+// single association and ignores the Assoc ID, it is certain to be the former, `one_to_one` tells
+// to return `Closed` for it.
+pub(crate) fn sctp_get_conn_state_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    one_to_one: bool,
+) -> std::io::Result<ConnState> {
+    match sctp_get_status_internal(fd, assoc_id) {
+        Ok(status) => Ok(status.state),
+        Err(e) if one_to_one && e.raw_os_error() == Some(libc::EINVAL) => Ok(ConnState::Closed),
+        Err(e) => Err(e),
+    }
+}
+
 // Get the status for the given Assoc ID
 pub(crate) fn sctp_get_status_internal(
     fd: &AsyncFd<RawFd>,
@@ -891,17 +2231,724 @@ pub(crate) fn sctp_get_status_internal(
         if result < 0 {
             Err(std::io::Error::last_os_error())
         } else {
-            Ok(sctp_status.try_into().unwrap())
+            Ok(sctp_status.into())
         }
     }
 }
 
-fn set_fd_non_blocking(fd: RawFd) -> std::io::Result<()> {
-    // Set Non Blocking
+// Get the Peer Address Parameters for the given Assoc ID and (optional) Peer Address.
+pub(crate) fn sctp_get_peer_addr_params_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    address: Option<SocketAddr>,
+) -> std::io::Result<PeerAddrParams> {
+    log::debug!(
+        "Getting Peer Address Parameters for {:?} using `getsockopt`.",
+        address
+    );
+
+    let mut params: PeerAddrParamsInternal = PeerAddrParams {
+        assoc_id,
+        address,
+        ..Default::default()
+    }
+    .into();
+    let mut params_size = std::mem::size_of::<PeerAddrParamsInternal>() as libc::socklen_t;
+
+    // Safety: `params` and `params_size` are valid in the current scope.
     unsafe {
-        let result = libc::fcntl(fd, libc::F_GETFL, 0);
-        if result < 0 {
-            return Err(std::io::Error::last_os_error());
+        let result = libc::getsockopt(
+            *fd.get_ref(),
+            SOL_SCTP,
+            SCTP_PEER_ADDR_PARAMS,
+            &mut params as *mut _ as *mut libc::c_void,
+            &mut params_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(params.into())
+        }
+    }
+}
+
+// Set the Peer Address Parameters.
+pub(crate) fn sctp_set_peer_addr_params_internal(
+    fd: &AsyncFd<RawFd>,
+    params: PeerAddrParams,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting Peer Address Parameters: {:?} using `setsockopt`.",
+        params
+    );
+
+    let params: PeerAddrParamsInternal = params.into();
+
+    // Safety: `params` is valid in the current scope.
+    unsafe {
+        let result = libc::setsockopt(
+            *fd.get_ref(),
+            SOL_SCTP,
+            SCTP_PEER_ADDR_PARAMS,
+            &params as *const _ as *const libc::c_void,
+            std::mem::size_of::<PeerAddrParamsInternal>()
+                .try_into()
+                .unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Set the Heartbeat Interval on all the current paths of the association.
+pub(crate) fn sctp_set_heartbeat_interval_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    interval: std::time::Duration,
+) -> std::io::Result<()> {
+    let hb_interval: u32 = interval.as_millis().try_into().map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "Heartbeat interval too large.",
+        )
+    })?;
+
+    // A zero `hb_interval` means 'no change', the kernel needs to be told explicitly otherwise.
+    let flags = if hb_interval == 0 {
+        SPP_HB_ENABLE | SPP_HB_TIME_IS_ZERO
+    } else {
+        SPP_HB_ENABLE
+    };
+
+    for address in sctp_getpaddrs_internal(fd, assoc_id)? {
+        sctp_set_peer_addr_params_internal(
+            fd,
+            PeerAddrParams {
+                assoc_id,
+                address: Some(address),
+                hb_interval,
+                flags,
+                ..Default::default()
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
+// Request an on demand heartbeat on the path to `addr` of the association.
+pub(crate) fn sctp_request_heartbeat_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    addr: SocketAddr,
+) -> std::io::Result<()> {
+    sctp_set_peer_addr_params_internal(
+        fd,
+        PeerAddrParams {
+            assoc_id,
+            address: Some(addr),
+            flags: SPP_HB_DEMAND,
+            ..Default::default()
+        },
+    )
+}
+
+// Number of the control chunks received on the association. Note: Linux resets the maximum
+// observed RTO (`sas_maxrto`) of the statistics on every read.
+fn sctp_received_ctrl_chunks_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<u64> {
+    // Safety: `AssocStats` is a C structure that is valid when zeroed.
+    let mut stats: AssocStats = unsafe { std::mem::zeroed() };
+    stats.assoc_id = assoc_id;
+    sctp_getsockopt_internal(fd, SCTP_GET_ASSOC_STATS, &mut stats)?;
+
+    Ok(stats.ictrlchunks)
+}
+
+// Measure the round trip time to the primary path of the association: request an on demand
+// heartbeat and wait for a control chunk (the `HEARTBEAT-ACK`) to be received. The kernel does not
+// report the acknowledgement of a heartbeat as such, any control chunk received is taken as the
+// acknowledgement. To not take the `SACK` of a message for it, the association is required to be
+// idle, ie. to have no outstanding data. The statistics are polled from every
+// `PING_POLL_INTERVAL` to every `PING_POLL_INTERVAL_MAX`, hence the round trip time is measured to
+// that granularity.
+pub(crate) async fn sctp_ping_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    timeout: std::time::Duration,
+) -> std::io::Result<std::time::Duration> {
+    let status = sctp_get_status_internal(fd, assoc_id)?;
+    if status.unacked_data != 0 || status.pending_data != 0 {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The association has outstanding data, ping needs an idle association.",
+        ));
+    }
+    let received = sctp_received_ctrl_chunks_internal(fd, assoc_id)?;
+
+    let start = std::time::Instant::now();
+    sctp_request_heartbeat_internal(fd, assoc_id, status.peer_primary.address)?;
+
+    let wait_for_ack = async {
+        let mut interval = PING_POLL_INTERVAL;
+        while sctp_received_ctrl_chunks_internal(fd, assoc_id)? == received {
+            tokio::time::sleep(interval).await;
+            interval = (interval * 2).min(PING_POLL_INTERVAL_MAX);
+        }
+        Ok(start.elapsed())
+    };
+
+    match tokio::time::timeout(timeout, wait_for_ack).await {
+        Ok(result) => result,
+        Err(_) => Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            "Heartbeat not acknowledged within the timeout.",
+        )),
+    }
+}
+
+// Request an on demand heartbeat on all the current paths of the association.
+pub(crate) fn sctp_probe_all_paths_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<()> {
+    for address in sctp_getpaddrs_internal(fd, assoc_id)? {
+        sctp_request_heartbeat_internal(fd, assoc_id, address)?;
+    }
+
+    Ok(())
+}
+
+// Set the DSCP of the packets sent on the path to `addr`, or to all the paths of the association
+// for an unspecified `addr`. `dscp` is the 6 bit code point, the kernel expects it in the upper 6
+// bits of the Traffic Class / TOS byte.
+pub(crate) fn sctp_set_dscp_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    addr: SocketAddr,
+    dscp: u8,
+) -> std::io::Result<()> {
+    if dscp > 0x3f {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "DSCP is a 6 bit code point (0 to 63).",
+        ));
+    }
+
+    sctp_set_peer_addr_params_internal(
+        fd,
+        PeerAddrParams {
+            assoc_id,
+            address: path_address(addr),
+            dscp: dscp << 2,
+            flags: SPP_DSCP,
+            ..Default::default()
+        },
+    )
+}
+
+// Set the IPv6 Flow Label of the packets sent on the path to `addr`, or to all the paths of the
+// association for an unspecified `addr`.
+pub(crate) fn sctp_set_ipv6_flowlabel_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    addr: SocketAddr,
+    flowlabel: u32,
+) -> std::io::Result<()> {
+    if flowlabel > 0xfffff {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "IPv6 Flow Label is a 20 bit value.",
+        ));
+    }
+
+    sctp_set_peer_addr_params_internal(
+        fd,
+        PeerAddrParams {
+            assoc_id,
+            address: path_address(addr),
+            ipv6_flowlabel: flowlabel,
+            flags: SPP_IPV6_FLOWLABEL,
+            ..Default::default()
+        },
+    )
+}
+
+// The Peer Address Parameters for an unspecified address (eg. `0.0.0.0:0`) apply to all the paths
+// of the association.
+fn path_address(addr: SocketAddr) -> Option<SocketAddr> {
+    if addr.ip().is_unspecified() {
+        None
+    } else {
+        Some(addr)
+    }
+}
+
+// Set the primary destination address of the peer for the given Assoc ID.
+//
+// The address is validated against the known peer addresses of the association first, so that
+// a clear error is returned, rather than the kernel's `EINVAL`.
+pub(crate) fn sctp_set_primary_addr_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    address: SocketAddr,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting primary address: {} for Association ID: {} using `setsockopt`.",
+        address,
+        assoc_id
+    );
+
+    let peer_addrs = sctp_getpaddrs_internal(fd, assoc_id)?;
+    if !peer_addrs.contains(&address) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!(
+                "{} is not a peer address of the association {} (peer addresses: {:?}).",
+                address, assoc_id, peer_addrs
+            ),
+        ));
+    }
+
+    let set_primary = SetPrimaryInternal {
+        assoc_id,
+        address: sockaddr_storage_from(Some(address)),
+    };
+
+    // Safety: `set_primary` is valid in the current scope.
+    unsafe {
+        let result = libc::setsockopt(
+            *fd.get_ref(),
+            SOL_SCTP,
+            SCTP_PRIMARY_ADDR,
+            &set_primary as *const _ as *const libc::c_void,
+            std::mem::size_of::<SetPrimaryInternal>()
+                .try_into()
+                .unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the value of a `SOL_SCTP` level socket option into `value`. `value` is also used as the
+// input for the options that need it (like the Association ID).
+pub(crate) fn sctp_getsockopt_internal<T>(
+    fd: &AsyncFd<RawFd>,
+    optname: libc::c_int,
+    value: &mut T,
+) -> std::io::Result<()> {
+    getsockopt_internal(fd, SOL_SCTP, optname, value)
+}
+
+// Set the value of a `SOL_SCTP` level socket option.
+pub(crate) fn sctp_setsockopt_internal<T>(
+    fd: &AsyncFd<RawFd>,
+    optname: libc::c_int,
+    value: &T,
+) -> std::io::Result<()> {
+    setsockopt_internal(fd, SOL_SCTP, optname, value)
+}
+
+// Get the value of a socket option at the given `level` into `value`.
+pub(crate) fn getsockopt_internal<T>(
+    fd: &AsyncFd<RawFd>,
+    level: libc::c_int,
+    optname: libc::c_int,
+    value: &mut T,
+) -> std::io::Result<()> {
+    let mut value_size = std::mem::size_of::<T>() as libc::socklen_t;
+
+    // Safety: `value` and `value_size` are valid in the current scope.
+    unsafe {
+        let result = libc::getsockopt(
+            *fd.get_ref(),
+            level,
+            optname,
+            value as *mut _ as *mut libc::c_void,
+            &mut value_size as *mut _ as *mut libc::socklen_t,
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Set the value of a socket option at the given `level`.
+pub(crate) fn setsockopt_internal<T>(
+    fd: &AsyncFd<RawFd>,
+    level: libc::c_int,
+    optname: libc::c_int,
+    value: &T,
+) -> std::io::Result<()> {
+    // Safety: `value` is valid in the current scope.
+    unsafe {
+        let result = libc::setsockopt(
+            *fd.get_ref(),
+            level,
+            optname,
+            value as *const _ as *const libc::c_void,
+            std::mem::size_of::<T>().try_into().unwrap(),
+        );
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Get the RTO parameters for the given Assoc ID.
+pub(crate) fn sctp_get_rto_info_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<RtoInfo> {
+    log::debug!("Getting `RtoInfo` using `getsockopt`.");
+
+    let mut rto_info = RtoInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    sctp_getsockopt_internal(fd, SCTP_RTOINFO, &mut rto_info)?;
+
+    Ok(rto_info)
+}
+
+// Set the RTO parameters. The values are validated by the kernel.
+pub(crate) fn sctp_set_rto_info_internal(
+    fd: &AsyncFd<RawFd>,
+    rto_info: RtoInfo,
+) -> std::io::Result<()> {
+    log::debug!("Setting `RtoInfo`: {:?} using `setsockopt`.", rto_info);
+
+    sctp_setsockopt_internal(fd, SCTP_RTOINFO, &rto_info).map_err(|e| {
+        if e.raw_os_error() == Some(libc::EINVAL) {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "Invalid RTO values {:?}, expected 'min <= initial <= max' ({}).",
+                    rto_info, e
+                ),
+            )
+        } else {
+            e
+        }
+    })
+}
+
+// Set the remote UDP encapsulation port for the given Assoc ID and Peer Address.
+#[cfg(target_os = "linux")]
+pub(crate) fn sctp_set_udp_encaps_port_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    address: SocketAddr,
+    port: u16,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting UDP encapsulation port: {} for {} (Association ID: {}) using `setsockopt`.",
+        port,
+        address,
+        assoc_id
+    );
+
+    use crate::types::internal::UdpEncapsInternal;
+
+    let encaps = UdpEncapsInternal {
+        assoc_id,
+        address: sockaddr_storage_from(Some(address)),
+        port: port.to_be(),
+    };
+
+    sctp_setsockopt_internal(fd, SCTP_REMOTE_UDP_ENCAPS_PORT, &encaps)
+}
+
+// Get the Association parameters for the given Assoc ID.
+pub(crate) fn sctp_get_assoc_info_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<AssocInfo> {
+    log::debug!("Getting `AssocInfo` using `getsockopt`.");
+
+    let mut assoc_info = AssocInfo {
+        assoc_id,
+        ..Default::default()
+    };
+    sctp_getsockopt_internal(fd, SCTP_ASSOCINFO, &mut assoc_info)?;
+
+    Ok(assoc_info)
+}
+
+// Set the Association parameters. Read-only values are ignored by the kernel.
+pub(crate) fn sctp_set_assoc_info_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_info: AssocInfo,
+) -> std::io::Result<()> {
+    log::debug!("Setting `AssocInfo`: {:?} using `setsockopt`.", assoc_info);
+
+    sctp_setsockopt_internal(fd, SCTP_ASSOCINFO, &assoc_info)
+}
+
+// Get whether the fragmentation of the user messages is disabled.
+pub(crate) fn sctp_get_disable_fragments_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<bool> {
+    log::debug!("Getting `SCTP_DISABLE_FRAGMENTS` using `getsockopt`.");
+
+    let mut on: libc::c_int = 0;
+    sctp_getsockopt_internal(fd, SCTP_DISABLE_FRAGMENTS, &mut on)?;
+
+    Ok(on != 0)
+}
+
+// Disable or enable the fragmentation of the user messages.
+pub(crate) fn sctp_set_disable_fragments_internal(
+    fd: &AsyncFd<RawFd>,
+    on: bool,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting `SCTP_DISABLE_FRAGMENTS`: {} using `setsockopt`.",
+        on
+    );
+
+    let on = on as libc::c_int;
+    sctp_setsockopt_internal(fd, SCTP_DISABLE_FRAGMENTS, &on)
+}
+
+// Set the Adaptation Layer Indication sent in the `INIT` / `INIT-ACK` of the future associations.
+pub(crate) fn sctp_set_adaptation_indication_internal(
+    fd: &AsyncFd<RawFd>,
+    ind: u32,
+) -> std::io::Result<()> {
+    log::debug!(
+        "Setting `SCTP_ADAPTATION_LAYER`: {:#x} using `setsockopt`.",
+        ind
+    );
+
+    let adaptation = SetAdaptation {
+        adaptation_ind: ind,
+    };
+    sctp_setsockopt_internal(fd, SCTP_ADAPTATION_LAYER, &adaptation)
+}
+
+// Enable or disable User Message Interleaving for the future associations. Interleaving requires
+// the fragment interleave level `2`, which is set first when enabling.
+pub(crate) fn sctp_set_interleaving_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io::Result<()> {
+    if on {
+        log::debug!("Setting `SCTP_FRAGMENT_INTERLEAVE`: 2 using `setsockopt`.");
+        let level: libc::c_int = 2;
+        sctp_setsockopt_internal(fd, SCTP_FRAGMENT_INTERLEAVE, &level)?;
+    }
+
+    log::debug!(
+        "Setting `SCTP_INTERLEAVING_SUPPORTED`: {} using `setsockopt`.",
+        on
+    );
+    sctp_set_assoc_value_internal(
+        fd,
+        SCTP_INTERLEAVING_SUPPORTED,
+        SCTP_FUTURE_ASSOC.into(),
+        on as u32,
+    )
+}
+
+// Get whether User Message Interleaving is negotiated on the association. For an association, the
+// kernel returns whether the peer is capable of interleaving, which is only set when it was
+// enabled locally as well.
+pub(crate) fn sctp_interleaving_active_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<bool> {
+    log::debug!("Getting `SCTP_INTERLEAVING_SUPPORTED` using `getsockopt`.");
+
+    Ok(sctp_get_assoc_value_internal(fd, SCTP_INTERLEAVING_SUPPORTED, assoc_id)? != 0)
+}
+
+// Get the value of a socket option using the `sctp_assoc_value` structure for the given Assoc ID.
+pub(crate) fn sctp_get_assoc_value_internal(
+    fd: &AsyncFd<RawFd>,
+    optname: libc::c_int,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    let mut assoc_value = AssocValue {
+        assoc_id,
+        ..Default::default()
+    };
+    sctp_getsockopt_internal(fd, optname, &mut assoc_value)?;
+
+    Ok(assoc_value.assoc_value)
+}
+
+// Set the value of a socket option using the `sctp_assoc_value` structure for the given Assoc ID.
+pub(crate) fn sctp_set_assoc_value_internal(
+    fd: &AsyncFd<RawFd>,
+    optname: libc::c_int,
+    assoc_id: AssociationId,
+    value: u32,
+) -> std::io::Result<()> {
+    let assoc_value = AssocValue {
+        assoc_id,
+        assoc_value: value,
+    };
+    sctp_setsockopt_internal(fd, optname, &assoc_value)
+}
+
+// Get the maximum fragment size for the given Assoc ID.
+pub(crate) fn sctp_get_maxseg_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+) -> std::io::Result<u32> {
+    log::debug!("Getting `SCTP_MAXSEG` using `getsockopt`.");
+
+    sctp_get_assoc_value_internal(fd, SCTP_MAXSEG, assoc_id)
+}
+
+// Set the maximum fragment size for the given Assoc ID.
+pub(crate) fn sctp_set_maxseg_internal(
+    fd: &AsyncFd<RawFd>,
+    assoc_id: AssociationId,
+    maxseg: u32,
+) -> std::io::Result<()> {
+    log::debug!("Setting `SCTP_MAXSEG`: {} using `setsockopt`.", maxseg);
+
+    sctp_set_assoc_value_internal(fd, SCTP_MAXSEG, assoc_id, maxseg)
+}
+
+// Returns an `SctpError::OneToManyOnly` error if the socket is not a One to Many socket. One to
+// Many sockets are `SOCK_SEQPACKET` sockets, while One to One sockets are `SOCK_STREAM` sockets.
+fn ensure_one_to_many_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<()> {
+    let mut sock_type: libc::c_int = 0;
+    getsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_TYPE, &mut sock_type)?;
+
+    if sock_type != libc::SOCK_SEQPACKET {
+        Err(SctpError::OneToManyOnly.into())
+    } else {
+        Ok(())
+    }
+}
+
+// Get the autoclose timeout (in seconds) of idle associations.
+pub(crate) fn sctp_get_autoclose_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<u32> {
+    log::debug!("Getting `SCTP_AUTOCLOSE` using `getsockopt`.");
+
+    ensure_one_to_many_internal(fd)?;
+
+    let mut secs: u32 = 0;
+    sctp_getsockopt_internal(fd, SCTP_AUTOCLOSE, &mut secs)?;
+
+    Ok(secs)
+}
+
+// Set the autoclose timeout (in seconds) of idle associations. `0` disables autoclose.
+pub(crate) fn sctp_set_autoclose_internal(fd: &AsyncFd<RawFd>, secs: u32) -> std::io::Result<()> {
+    log::debug!("Setting `SCTP_AUTOCLOSE`: {} using `setsockopt`.", secs);
+
+    ensure_one_to_many_internal(fd)?;
+
+    sctp_setsockopt_internal(fd, SCTP_AUTOCLOSE, &secs)
+}
+
+// Get the `SO_PRIORITY` of the socket.
+#[cfg(target_os = "linux")]
+pub(crate) fn get_priority_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<u32> {
+    let mut prio: libc::c_int = 0;
+    getsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_PRIORITY, &mut prio)?;
+
+    Ok(prio as u32)
+}
+
+// Set the `SO_PRIORITY` of the socket, used for the egress queueing of the packets.
+#[cfg(target_os = "linux")]
+pub(crate) fn set_priority_internal(fd: &AsyncFd<RawFd>, prio: u32) -> std::io::Result<()> {
+    log::debug!("Setting `SO_PRIORITY`: {} using `setsockopt`.", prio);
+
+    let prio = prio as libc::c_int;
+    setsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_PRIORITY, &prio)
+}
+
+// Get the size of the receive buffer (`SO_RCVBUF`) of the socket.
+pub(crate) fn get_recv_buffer_size_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<usize> {
+    let mut size: libc::c_int = 0;
+    getsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_RCVBUF, &mut size)?;
+
+    Ok(size as usize)
+}
+
+// Set the size of the receive buffer (`SO_RCVBUF`) of the socket.
+pub(crate) fn set_recv_buffer_size_internal(
+    fd: &AsyncFd<RawFd>,
+    size: usize,
+) -> std::io::Result<()> {
+    log::debug!("Setting `SO_RCVBUF`: {} using `setsockopt`.", size);
+
+    let size = buffer_size(size)?;
+    setsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_RCVBUF, &size)
+}
+
+// Get the size of the send buffer (`SO_SNDBUF`) of the socket.
+pub(crate) fn get_send_buffer_size_internal(fd: &AsyncFd<RawFd>) -> std::io::Result<usize> {
+    let mut size: libc::c_int = 0;
+    getsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_SNDBUF, &mut size)?;
+
+    Ok(size as usize)
+}
+
+// Set the size of the send buffer (`SO_SNDBUF`) of the socket.
+pub(crate) fn set_send_buffer_size_internal(
+    fd: &AsyncFd<RawFd>,
+    size: usize,
+) -> std::io::Result<()> {
+    log::debug!("Setting `SO_SNDBUF`: {} using `setsockopt`.", size);
+
+    let size = buffer_size(size)?;
+    setsockopt_internal(fd, libc::SOL_SOCKET, libc::SO_SNDBUF, &size)
+}
+
+fn buffer_size(size: usize) -> std::io::Result<libc::c_int> {
+    size.try_into().map_err(|_| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "Buffer size too large.")
+    })
+}
+
+// Set the `O_NONBLOCK` flag of the socket. Clearing it is rejected: the socket is registered with
+// the `tokio` reactor (`AsyncFd`), where a blocking call would block the runtime thread.
+pub(crate) fn set_nonblocking_internal(fd: &AsyncFd<RawFd>, on: bool) -> std::io::Result<()> {
+    if !on {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "The blocking mode cannot be used on a socket driven by the `tokio` runtime.",
+        ));
+    }
+
+    // Safety: This is just a wrapper over `libc` calls on a valid file descriptor.
+    unsafe {
+        let rawfd = *fd.get_ref();
+        let result = libc::fcntl(rawfd, libc::F_GETFL, 0);
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let result = libc::fcntl(rawfd, libc::F_SETFL, result | libc::O_NONBLOCK);
+        if result < 0 {
+            Err(std::io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn set_fd_non_blocking(fd: RawFd) -> std::io::Result<()> {
+    // Set Non Blocking
+    unsafe {
+        let result = libc::fcntl(fd, libc::F_GETFL, 0);
+        if result < 0 {
+            return Err(std::io::Error::last_os_error());
         }
         let flags = result | libc::O_NONBLOCK;
         let result = libc::fcntl(fd, libc::F_SETFL, flags);
@@ -923,3 +2970,155 @@ pub(crate) fn close_internal(fd: &AsyncFd<RawFd>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnsupportedNotificationPolicy;
+
+    // A `SOCK_SEQPACKET` Unix socket pair standing in for an SCTP socket, for the receive paths
+    // that process the messages held in the receive state.
+    fn socketpair() -> (AsyncFd<RawFd>, RawFd) {
+        let mut fds = [0 as RawFd; 2];
+        // Safety: `fds` is valid in the current scope.
+        let result = unsafe {
+            libc::socketpair(
+                libc::AF_UNIX,
+                libc::SOCK_SEQPACKET | libc::SOCK_NONBLOCK,
+                0,
+                fds.as_mut_ptr(),
+            )
+        };
+        assert_eq!(result, 0, "{:#?}", std::io::Error::last_os_error());
+
+        (AsyncFd::new(fds[0]).unwrap(), fds[1])
+    }
+
+    fn unsupported_notification() -> (ReceivedMessageInfo, Vec<u8>) {
+        // An unknown notification type, parsed as `Unsupported`.
+        let mut unknown = vec![];
+        unknown.extend(0x80ff_u16.to_ne_bytes());
+        unknown.extend(0_u16.to_ne_bytes());
+        unknown.extend(8_u32.to_ne_bytes());
+        let info = ReceivedMessageInfo {
+            notification: true,
+            rcv_info: None,
+            nxt_info: None,
+            eor: true,
+        };
+
+        (info, unknown)
+    }
+
+    fn data(payload: &[u8]) -> (ReceivedMessageInfo, Vec<u8>) {
+        data_part(payload, true)
+    }
+
+    fn data_part(payload: &[u8], eor: bool) -> (ReceivedMessageInfo, Vec<u8>) {
+        let info = ReceivedMessageInfo {
+            notification: false,
+            rcv_info: None,
+            nxt_info: None,
+            eor,
+        };
+
+        (info, payload.to_vec())
+    }
+
+    #[tokio::test]
+    async fn receive_bounded_returns_notifications_between_parts() {
+        let (fd, peer) = socketpair();
+        let state = ReceiveState::without_reassembly();
+
+        let (info, part) = data_part(b"hello ", false);
+        state.pending.push(info, part);
+        let (info, unknown) = unsupported_notification();
+        state.pending.push(info, unknown);
+        let (info, part) = data_part(b"world!", true);
+        state.pending.push(info, part);
+
+        let result = sctp_recvmsg_reassembled_internal(&fd, &state, Reassembly::Bounded(16)).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert_eq!(
+            result.unwrap(),
+            NotificationOrData::Notification(Notification::Unsupported)
+        );
+
+        let result = sctp_recvmsg_reassembled_internal(&fd, &state, Reassembly::Bounded(16)).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        match result.unwrap() {
+            NotificationOrData::Data(ReceivedData { payload, .. }) => {
+                assert_eq!(payload, b"hello world!".to_vec());
+            }
+            x => panic!("Should never come here!: {:#?}", x),
+        }
+
+        let (info, part) = data_part(b"hello ", false);
+        state.pending.push(info, part);
+        let (info, part) = data_part(b"world!", true);
+        state.pending.push(info, part);
+        let result = sctp_recvmsg_reassembled_internal(&fd, &state, Reassembly::Bounded(8)).await;
+        assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+        let err = result.err().unwrap();
+        assert_eq!(
+            SctpError::from_io_error(&err),
+            Some(&SctpError::MessageTooLarge { size: 12, max: 8 })
+        );
+
+        close_internal(&fd);
+        // Safety: `peer` is owned by the test.
+        unsafe {
+            libc::close(peer);
+        }
+    }
+
+    #[tokio::test]
+    async fn receive_state_applies_unsupported_notification_policy() {
+        let (fd, peer) = socketpair();
+        let state = ReceiveState::without_reassembly();
+
+        let (info, unknown) = unsupported_notification();
+        state.pending.push(info, unknown);
+        let result = sctp_try_recvmsg_reassembled_internal(&fd, &state);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert_eq!(
+            result.unwrap(),
+            Some(NotificationOrData::Notification(Notification::Unsupported))
+        );
+
+        state.policy.set(UnsupportedNotificationPolicy::Skip);
+        let (info, unknown) = unsupported_notification();
+        state.pending.push(info, unknown);
+        let (info, payload) = data(b"hello world!");
+        state.pending.push(info, payload);
+        let result = sctp_recvmsg_reassembled_internal(&fd, &state, Reassembly::Configured).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        match result.unwrap() {
+            NotificationOrData::Data(ReceivedData { payload, .. }) => {
+                assert_eq!(payload, b"hello world!".to_vec());
+            }
+            x => panic!("Should never come here!: {:#?}", x),
+        }
+
+        state.policy.set(UnsupportedNotificationPolicy::Error);
+        let (info, unknown) = unsupported_notification();
+        state.pending.push(info, unknown);
+        let result = sctp_try_recvmsg_reassembled_internal(&fd, &state);
+        assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+        let err = result.err().unwrap();
+        assert_eq!(
+            SctpError::from_io_error(&err),
+            Some(&SctpError::UnsupportedNotification)
+        );
+
+        let result = sctp_try_recvmsg_reassembled_internal(&fd, &state);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert!(result.unwrap().is_none());
+
+        close_internal(&fd);
+        // Safety: `peer` is owned by the test.
+        unsafe {
+            libc::close(peer);
+        }
+    }
+}
@@ -50,19 +50,59 @@
 //! # }
 //! ```
 
+mod builder;
 mod connected_socket;
+mod defaults;
+mod incoming;
 mod listener;
+mod notification_queue;
+mod one_to_many;
+mod one_to_one;
+mod reassembler;
 mod socket;
+mod split;
+mod ssn_tracker;
+mod worker_pool;
 
 #[doc(inline)]
 pub use socket::Socket;
 
+#[doc(inline)]
+pub use builder::{ClientBuilder, HandshakeParams, SocketBuilder};
+
 #[doc(inline)]
 pub use listener::Listener;
 
 #[doc(inline)]
 pub use connected_socket::ConnectedSocket;
 
+#[doc(inline)]
+pub use defaults::SctpDefaults;
+
+#[doc(inline)]
+pub use incoming::Incoming;
+
+#[doc(inline)]
+pub use notification_queue::{NotificationQueue, QueueFullPolicy};
+
+#[doc(inline)]
+pub use one_to_one::OneToOneSocket;
+
+#[doc(inline)]
+pub use one_to_many::OneToManySocket;
+
+#[doc(inline)]
+pub use reassembler::MessageReassembler;
+
+#[doc(inline)]
+pub use split::{SctpReceiver, SctpSender};
+
+#[doc(inline)]
+pub use ssn_tracker::{SsnGap, StreamSsnTracker};
+
+#[doc(inline)]
+pub use worker_pool::WorkerPool;
+
 mod internal;
 
 mod consts;
@@ -71,7 +111,10 @@ mod types;
 
 #[doc(inline)]
 pub use types::{
-    AssocChangeState, AssociationChange, AssociationId, BindxFlags, CmsgType, ConnStatus, Event,
-    Notification, NotificationOrData, NxtInfo, RcvInfo, ReceivedData, SendData, SendInfo, Shutdown,
-    SocketToAssociation, SubscribeEventAssocId,
+    AdaptationEvent, AssocChangeState, AssocFeatures, AssocInfo, AssociationChange, AssociationId,
+    AuthKeyState, BindxFlags, CmsgType, ConnState, ConnStatus, Event, Notification,
+    NotificationOrData, NxtInfo, PdapiEvent, PeerAddrChange, PeerAddrParams, PeerAddrState,
+    RcvInfo, ReceivedData, ReceivedMessageInfo, RtoInfo, SctpError, SendData, SendFailed,
+    SendFlags, SendInfo, Shutdown, SocketToAssociation, SubscribeEventAssocId,
+    UnsupportedNotificationPolicy,
 };
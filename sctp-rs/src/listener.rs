@@ -2,14 +2,19 @@
 
 use std::net::SocketAddr;
 use std::os::unix::io::RawFd;
+use std::sync::Arc;
 
 use tokio::io::unix::AsyncFd;
 
+use crate::consts::{SCTP_ABORT, SCTP_EOF};
 #[allow(unused)]
 use crate::internal::*;
+use crate::types::internal::{AcceptFilter, AuthKeys, Reassembly, ReceiveState};
 use crate::{
-    types::AssociationId, BindxFlags, ConnStatus, ConnectedSocket, Event, NotificationOrData,
-    SendData, SubscribeEventAssocId,
+    types::AssociationId, AssocInfo, AuthKeyState, BindxFlags, ConnState, ConnStatus,
+    ConnectedSocket, Event, NotificationOrData, PeerAddrParams, ReceivedMessageInfo, RtoInfo,
+    SendData, SendInfo, Socket, SocketToAssociation, SubscribeEventAssocId,
+    UnsupportedNotificationPolicy,
 };
 
 /// A structure representing a socket that is listening for incoming SCTP Connections.
@@ -20,12 +25,93 @@ use crate::{
 /// [`Socket::listen`][crate::Socket::listen] for more details.
 pub struct Listener {
     inner: AsyncFd<RawFd>,
+    auth_keys: AuthKeys,
+    state: ReceiveState,
+    accept_filter: AcceptFilter,
 }
 
 impl Listener {
+    /// Create a socket bound to all the `addrs` and listening on it.
+    ///
+    /// This is a convenience for the common case of a multi-homed listener. The socket is bound
+    /// to the first address and the remaining addresses are added using
+    /// [`sctp_bindx`][Self::sctp_bindx]. An IPv6 family socket is created if any of the `addrs` is
+    /// an IPv6 address (IPv4 addresses are then bound as IPv4 mapped addresses), otherwise an
+    /// IPv4 family socket is created. The socket listens with a `backlog` of 1024, use
+    /// [`Socket::listen`][crate::Socket::listen] for a different `backlog`.
+    ///
+    /// An error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput] is returned if
+    /// `addrs` is empty or if the addresses do not all use the same port.
+    pub fn bind_multi(
+        addrs: &[SocketAddr],
+        assoc: SocketToAssociation,
+    ) -> std::io::Result<Listener> {
+        let first = addrs.first().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "At least one address is required to bind.",
+            )
+        })?;
+        if addrs.iter().any(|addr| addr.port() != first.port()) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "All the addresses bound to an SCTP socket should use the same port.",
+            ));
+        }
+
+        let socket = if addrs.iter().any(|addr| addr.is_ipv6()) {
+            Socket::new_v6(assoc)?
+        } else {
+            Socket::new_v4(assoc)?
+        };
+        socket.bind(*first)?;
+        if addrs.len() > 1 {
+            socket.sctp_bindx(&addrs[1..], BindxFlags::Add)?;
+        }
+
+        socket.listen(1024)
+    }
+
     /// Accept on a given socket (valid only for `OneToOne` type sockets).
+    ///
+    /// The connections from the peers rejected by the accept filter (See
+    /// [`set_accept_filter`][Self::set_accept_filter]) are closed and skipped.
     pub async fn accept(&self) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
-        accept_internal(&self.inner).await
+        loop {
+            let (accepted, addr) = accept_internal(&self.inner).await?;
+            if self.accept_filter.accepts(addr) {
+                return Ok((self.with_endpoint_auth_keys(accepted), addr));
+            }
+            log::debug!("Closing the connection from the rejected peer: {}", addr);
+        }
+    }
+
+    /// Accept a pending connection without waiting (valid only for `OneToOne` type sockets).
+    ///
+    /// The [`Listener`] is always non-blocking. When no connection is pending, an error of the
+    /// kind [`WouldBlock`][std::io::ErrorKind::WouldBlock] is returned, this allows integrating
+    /// the [`Listener`] with other event loops. Use [`accept`][Self::accept] to wait for a
+    /// connection instead.
+    pub fn try_accept(&self) -> std::io::Result<(ConnectedSocket, SocketAddr)> {
+        loop {
+            let (accepted, addr) = try_accept_internal(&self.inner)?;
+            if self.accept_filter.accepts(addr) {
+                return Ok((self.with_endpoint_auth_keys(accepted), addr));
+            }
+            log::debug!("Closing the connection from the rejected peer: {}", addr);
+        }
+    }
+
+    /// Set the filter of the peers whose connections are accepted (valid only for `OneToOne` type
+    /// sockets).
+    ///
+    /// The `filter` is called with the address of the peer of every accepted connection, the
+    /// connections for which it returns `false` are closed by [`accept`][Self::accept] (and
+    /// [`try_accept`][Self::try_accept]) and never returned. This is a lightweight access control,
+    /// the association with the rejected peer is established before it is closed. Setting a new
+    /// filter replaces the previous one.
+    pub fn set_accept_filter(&self, filter: impl Fn(SocketAddr) -> bool + Send + Sync + 'static) {
+        self.accept_filter.set(Arc::new(filter))
     }
 
     /// Shutdown on the socket
@@ -46,7 +132,9 @@ impl Listener {
     /// `[Listener::accept`] where peeled off socket behaves like a stand alone
     /// one-to-one socket.
     pub fn sctp_peeloff(&self, assoc_id: AssociationId) -> std::io::Result<ConnectedSocket> {
-        sctp_peeloff_internal(&self.inner, assoc_id)
+        let peeled_off =
+            ConnectedSocket::from_rawfd(sctp_peeloff_internal(&self.inner, assoc_id)?)?;
+        Ok(peeled_off.with_auth_keys(self.auth_keys.for_association(assoc_id)))
     }
 
     /// Get Peer Address(es) for the given Association ID. See: Section 9.3 RFC 6458
@@ -63,24 +151,103 @@ impl Listener {
         sctp_getladdrs_internal(&self.inner, assoc_id)
     }
 
+    /// Get the local address of the socket.
+    ///
+    /// See [`Socket::local_addr`][crate::Socket::local_addr] for details.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        local_addr_internal(&self.inner)
+    }
+
+    /// Whether the socket is an SCTP socket, ie. its protocol (`SO_PROTOCOL`) is `IPPROTO_SCTP`.
+    ///
+    /// See [`Socket::is_sctp`][crate::Socket::is_sctp] for details.
+    pub fn is_sctp(&self) -> bool {
+        is_sctp_internal(&self.inner)
+    }
+
     /// Receive Data or Notification from the listening socket.
     ///
     /// In the case of One-to-many sockets, it is possible to receive on the listening socket,
     /// without explicitly 'accept'ing or 'peeling off' the socket. The internal API used to
     /// receive the data is also the API used to receive notifications. This function returns
     /// either the notification (which the user should have subscribed for) or the data.
+    ///
+    /// Unlike [`ConnectedSocket::sctp_recv`], the messages received in multiple parts are not
+    /// reassembled, each part is returned as is. Use [`recv_into`][Self::recv_into] to tell the
+    /// last part of a message.
     pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
-        sctp_recvmsg_internal(&self.inner).await
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Configured).await
+    }
+
+    /// Receive Data or Notification into the given buffer.
+    ///
+    /// See [`ConnectedSocket::recv_into`][crate::ConnectedSocket::recv_into] for details.
+    pub async fn recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedMessageInfo> {
+        sctp_recvmsg_into_resubscribe_internal(&self.inner, &self.state, buf).await
+    }
+
+    /// Receive Data or Notification from the socket without waiting.
+    ///
+    /// See [`ConnectedSocket::try_recv`][crate::ConnectedSocket::try_recv] for details.
+    pub fn try_recv(&self) -> std::io::Result<Option<NotificationOrData>> {
+        sctp_try_recvmsg_reassembled_internal(&self.inner, &self.state)
+    }
+
+    /// Set the non-blocking mode of the socket.
+    ///
+    /// See [`ConnectedSocket::set_nonblocking`][crate::ConnectedSocket::set_nonblocking] for
+    /// details.
+    pub fn set_nonblocking(&self, on: bool) -> std::io::Result<()> {
+        set_nonblocking_internal(&self.inner, on)
     }
 
     /// Send Data and Anciliary data if any on the SCTP Socket.
     ///
     /// SCTP supports sending the actual SCTP message together with sending any anciliary data on
     /// the SCTP association. The anciliary data is optional.
+    ///
+    /// SCTP cannot carry zero length messages, an empty `payload` returns an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput]. For application keepalives use
+    /// [`set_heartbeat_interval`][Self::set_heartbeat_interval] instead.
+    ///
+    /// For One to Many sockets, the send sets up a new association with `to`, if there is none
+    /// yet. Its Association ID can be looked up using [`peer_assoc_id`][Self::peer_assoc_id].
     pub async fn sctp_send(&self, to: SocketAddr, data: SendData) -> std::io::Result<()> {
         sctp_sendmsg_internal(&self.inner, Some(to), data).await
     }
 
+    /// Get the Association ID of the association with the peer address `addr`.
+    ///
+    /// Returns an error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput] if there
+    /// is no association with `addr`.
+    pub fn peer_assoc_id(&self, addr: SocketAddr) -> std::io::Result<AssociationId> {
+        sctp_peer_addr_assoc_id_internal(&self.inner, addr)
+    }
+
+    /// Abort the given association with a user initiated abort reason.
+    ///
+    /// The association is torn down immediately by sending an ABORT chunk carrying `reason` as
+    /// the 'User Initiated Abort' cause (`SCTP_ABORT` flag, See Section 5.3.4 of RFC 6458). Any
+    /// data not yet delivered is discarded. The peer sees an
+    /// [`AssociationChange`][crate::AssociationChange] notification with the state
+    /// [`CommLost`][crate::AssocChangeState::CommLost].
+    pub async fn abort(&self, assoc_id: AssociationId, reason: &[u8]) -> std::io::Result<()> {
+        sctp_send_flags_internal(&self.inner, assoc_id, SCTP_ABORT, reason).await
+    }
+
+    /// Gracefully shutdown the given association.
+    ///
+    /// A graceful shutdown of the association is initiated (`SCTP_EOF` flag, See Section 5.3.4 of
+    /// RFC 6458), any data that is already queued is delivered before the association is closed.
+    /// The peer sees a [`Shutdown`][crate::Shutdown] notification followed by an
+    /// [`AssociationChange`][crate::AssociationChange] notification with the state
+    /// [`ShutdownComplete`][crate::AssocChangeState::ShutdownComplete].
+    /// This is mostly useful for the One to Many sockets, where the socket itself cannot be
+    /// `shutdown` without affecting other associations.
+    pub async fn shutdown_assoc(&self, assoc_id: AssociationId) -> std::io::Result<()> {
+        sctp_send_flags_internal(&self.inner, assoc_id, SCTP_EOF, &[]).await
+    }
+
     /// Subscribe to a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
     ///
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
@@ -107,6 +274,20 @@ impl Listener {
         sctp_subscribe_event_internal(&self.inner, event, assoc_id, false)
     }
 
+    /// Subscribe to the `events` again, whenever an association of this socket is restarted.
+    ///
+    /// See [`ConnectedSocket::set_resubscribe_on_restart`] for details.
+    pub fn set_resubscribe_on_restart(&self, events: &[Event]) {
+        self.state.resubscribe.set(events);
+    }
+
+    /// Set what is done when a Notification not supported by this crate is received.
+    ///
+    /// See [`ConnectedSocket::set_unsupported_notification_policy`] for details.
+    pub fn set_unsupported_notification_policy(&self, policy: UnsupportedNotificationPolicy) {
+        self.state.policy.set(policy);
+    }
+
     /// Subscribe to SCTP Events. See section 6.2.1 of RFC6458.
     ///
     /// SCTP allows receiving notifications about the changes to SCTP associations etc from the
@@ -128,10 +309,7 @@ impl Listener {
         if failures.is_empty() {
             Ok(())
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
+            Err(std::io::Error::other(format!("{:?}", failures)))
         }
     }
 
@@ -154,16 +332,25 @@ impl Listener {
         if failures.is_empty() {
             Ok(())
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
+            Err(std::io::Error::other(format!("{:?}", failures)))
         }
     }
 
+    /// Subscribe to all the SCTP Events that are delivered as notifications.
+    ///
+    /// This is a convenience API mostly useful while debugging, where it is desirable to see the
+    /// full notification stream. All [`Event`]s except [`Event::DataIo`] are subscribed to and
+    /// the first subscription error (if any) is returned.
+    pub fn enable_all_events(&self, assoc_id: SubscribeEventAssocId) -> std::io::Result<()> {
+        sctp_enable_all_events_internal(&self.inner, assoc_id)
+    }
+
     /// Setup parameters for a new association.
     ///
-    /// To specify custom parameters for a new association this API is used.
+    /// To specify custom parameters for a new association this API is used. The parameters are
+    /// used only for the associations set up after this call, hence for the One to One sockets,
+    /// this should be called before `listen` or `connect`. (The associations `accept`ed on a One
+    /// to One [`Listener`][crate::Listener] use the parameters of the [`Listener`][crate::Listener].)
     pub fn sctp_setup_init_params(
         &self,
         ostreams: u16,
@@ -195,12 +382,426 @@ impl Listener {
         sctp_get_status_internal(&self.inner, assoc_id)
     }
 
+    /// Get the state of the association, eg. to follow the progress of a graceful shutdown.
+    ///
+    /// After a [`shutdown`][Self::shutdown], the association goes through the `ShutdownSent`
+    /// (and `ShutdownPending` if there is unacknowledged data) states, or the `ShutdownReceived`
+    /// and `ShutdownAckSent` states if the peer initiated the shutdown. The association is gone
+    /// once the peer completes the shutdown as well. Unlike on a
+    /// [`ConnectedSocket`][crate::ConnectedSocket::shutdown_state], the kernel reports a gone
+    /// association the same as an unknown association ID, hence the error (`EINVAL`) is returned
+    /// then rather than [`ConnState::Closed`].
+    pub fn shutdown_state(&self, assoc_id: AssociationId) -> std::io::Result<ConnState> {
+        sctp_get_conn_state_internal(&self.inner, assoc_id, false)
+    }
+
+    /// Get the Path MTU of the given association, ie. the smallest Path MTU of all the paths
+    /// to the peer.
+    ///
+    /// The data may be sent on any of the paths (eg. after a failover), so this is the MTU that
+    /// is relevant for sizing the messages, rather than the MTU of the primary path alone.
+    pub fn association_pmtu(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_association_pmtu_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the fragmentation point of the given association.
+    ///
+    /// This is the largest message (in bytes) that is sent without being fragmented into
+    /// multiple `DATA` chunks (See also [`ConnStatus`]). Sizing application messages to this
+    /// value allows them to be delivered in a single packet.
+    pub fn fragmentation_point(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        Ok(sctp_get_status_internal(&self.inner, assoc_id)?.fragmentation_pt)
+    }
+
+    /// Set Default `SendInfo` values for the given association.
+    ///
+    /// In the case of One to Many sockets, the data is sent on the listening socket itself and
+    /// this API can be used to set the default `SendInfo` (stream ID, PPID and context) used by
+    /// [`sctp_send`][`Self::sctp_send`] when no `snd_info` is passed. An `assoc_id` of `0` sets
+    /// the defaults for the future associations.
+    pub fn set_default_sndinfo(
+        &self,
+        info: SendInfo,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<()> {
+        sctp_set_default_sendinfo_internal(&self.inner, SendInfo { assoc_id, ..info })
+    }
+
+    /// Get the Default `SendInfo` values for the given association.
+    pub fn get_default_sndinfo(&self, assoc_id: AssociationId) -> std::io::Result<SendInfo> {
+        sctp_get_default_sendinfo_internal(&self.inner, assoc_id)
+    }
+
     // functions not part of public APIs
     pub(crate) fn from_rawfd(fd: RawFd) -> std::io::Result<Self> {
         Ok(Self {
             inner: AsyncFd::new(fd)?,
+            auth_keys: AuthKeys::default(),
+            state: ReceiveState::without_reassembly(),
+            accept_filter: AcceptFilter::default(),
         })
     }
+
+    pub(crate) fn with_auth_keys(mut self, auth_keys: AuthKeys) -> Self {
+        self.auth_keys = auth_keys;
+        self
+    }
+
+    // The accepted socket inherits the keys of the endpoint.
+    fn with_endpoint_auth_keys(&self, accepted: ConnectedSocket) -> ConnectedSocket {
+        accepted.with_auth_keys(self.auth_keys.for_association(AssociationId::default()))
+    }
+
+    /// Get the Peer Address Parameters for the given association. See Section 8.1.12 of RFC 6458.
+    ///
+    /// When `address` is `None`, the parameters for the association (or the socket defaults) are
+    /// returned, otherwise the parameters for the given path (Peer Address) are returned.
+    pub fn peer_addr_params(
+        &self,
+        assoc_id: AssociationId,
+        address: Option<SocketAddr>,
+    ) -> std::io::Result<PeerAddrParams> {
+        sctp_get_peer_addr_params_internal(&self.inner, assoc_id, address)
+    }
+
+    /// Set the Peer Address Parameters. See Section 8.1.12 of RFC 6458.
+    ///
+    /// The Retransmission Timeout (RTO) cannot be set per path, see [`PeerAddrParams`].
+    pub fn set_peer_addr_params(&self, params: PeerAddrParams) -> std::io::Result<()> {
+        sctp_set_peer_addr_params_internal(&self.inner, params)
+    }
+
+    /// Set the Heartbeat Interval for all the current paths of the given association.
+    ///
+    /// Heartbeats are enabled and `interval` (with a miliseconds granularity) is set on each of
+    /// the peer addresses returned by [`sctp_getpaddrs`][`Self::sctp_getpaddrs`]. Paths added to
+    /// the association later are not affected.
+    pub fn set_heartbeat_interval(
+        &self,
+        assoc_id: AssociationId,
+        interval: std::time::Duration,
+    ) -> std::io::Result<()> {
+        sctp_set_heartbeat_interval_internal(&self.inner, assoc_id, interval)
+    }
+
+    /// Request an on demand heartbeat on the path to `addr` of the given association. See Section
+    /// 8.1.12 of RFC 6458.
+    ///
+    /// The heartbeat is sent right away, irrespective of the Heartbeat Interval. The result of the
+    /// probe is reported by the [`PeerAddrChange`][crate::PeerAddrChange] notifications, when the
+    /// state of the path changes.
+    pub fn request_heartbeat(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<()> {
+        sctp_request_heartbeat_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Measure the round trip time to the peer of the given association.
+    ///
+    /// See [`ConnectedSocket::ping`] for details.
+    pub async fn ping(
+        &self,
+        assoc_id: AssociationId,
+        timeout: std::time::Duration,
+    ) -> std::io::Result<std::time::Duration> {
+        sctp_ping_internal(&self.inner, assoc_id, timeout).await
+    }
+
+    /// Request an on demand heartbeat on all the current paths of the given association.
+    ///
+    /// This allows detecting the dead paths (or a half open association) proactively, before
+    /// sending the data. See [`request_heartbeat`][Self::request_heartbeat] for details.
+    pub fn probe_all_paths(&self, assoc_id: AssociationId) -> std::io::Result<()> {
+        sctp_probe_all_paths_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Differentiated Services Code Point (DSCP) of the packets sent on the path to `addr`
+    /// of the given association. See Section 8.1.12 of RFC 6458.
+    ///
+    /// An unspecified `addr` (eg. `0.0.0.0:0`) sets the DSCP for all the paths of the
+    /// association. `dscp` is the 6 bit code point (eg. `46` for Expedited Forwarding) and not the
+    /// Traffic Class / TOS byte, it is shifted into the upper 6 bits of that byte internally. Hence
+    /// the `dscp` of the [`PeerAddrParams`] reads back as `dscp << 2`.
+    pub fn set_dscp(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        dscp: u8,
+    ) -> std::io::Result<()> {
+        sctp_set_dscp_internal(&self.inner, assoc_id, addr, dscp)
+    }
+
+    /// Set the IPv6 Flow Label (20 bits) of the packets sent on the path to `addr` of the given
+    /// association. See Section 8.1.12 of RFC 6458.
+    ///
+    /// An unspecified `addr` (eg. `[::]:0`) sets the Flow Label for all the paths of the
+    /// association.
+    pub fn set_ipv6_flowlabel(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        flowlabel: u32,
+    ) -> std::io::Result<()> {
+        sctp_set_ipv6_flowlabel_internal(&self.inner, assoc_id, addr, flowlabel)
+    }
+
+    /// Set the primary destination address of the peer for the given association. See Section
+    /// 8.1.9 of RFC 6458.
+    ///
+    /// `addr` must be one of the peer addresses of the association (See
+    /// [`sctp_getpaddrs`][`Self::sctp_getpaddrs`]), otherwise an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput] is returned. When subscribed to
+    /// [`Event::Address`], a [`PeerAddrChange`][crate::PeerAddrChange] notification with the state
+    /// [`MadePrim`][crate::PeerAddrState::MadePrim] is received once the primary is changed.
+    pub fn set_primary_addr(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+    ) -> std::io::Result<()> {
+        sctp_set_primary_addr_internal(&self.inner, assoc_id, addr)
+    }
+
+    /// Get the Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` returns the socket wide defaults for the future associations.
+    pub fn get_rto_info(&self, assoc_id: AssociationId) -> std::io::Result<RtoInfo> {
+        sctp_get_rto_info_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` sets the socket wide defaults for the future associations. The
+    /// values are validated by the kernel, invalid values (eg. `min` larger than `max`) return an
+    /// error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+    pub fn set_rto_info(&self, rto_info: RtoInfo) -> std::io::Result<()> {
+        sctp_set_rto_info_internal(&self.inner, rto_info)
+    }
+
+    /// Get the Association parameters. See Section 8.1.2 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` returns the socket wide defaults for the future associations.
+    pub fn get_assoc_info(&self, assoc_id: AssociationId) -> std::io::Result<AssocInfo> {
+        sctp_get_assoc_info_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Association parameters. See Section 8.1.2 of RFC 6458.
+    ///
+    /// Only `max_rxt` and `cookie_life` can be set, the read-only values of the [`AssocInfo`]
+    /// (`peer_destinations`, `peer_rwnd` and `local_rwnd`) are ignored and are not changed.
+    pub fn set_assoc_info(&self, assoc_info: AssocInfo) -> std::io::Result<()> {
+        sctp_set_assoc_info_internal(&self.inner, assoc_info)
+    }
+
+    /// Get the maximum size of the `DATA` chunks (fragments) of the user messages. See Section
+    /// 8.1.16 of RFC 6458.
+    ///
+    /// A value of `0` means the fragment size is determined by the Path MTU.
+    pub fn maxseg(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_maxseg_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum size of the `DATA` chunks (fragments) of the user messages. See Section
+    /// 8.1.16 of RFC 6458.
+    ///
+    /// A `maxseg` of `0` lets the fragment size be determined by the Path MTU. The kernel never
+    /// uses a size larger than allowed by the Path MTU, the effective value can be read back from
+    /// the `fragmentation_pt` of the [`ConnStatus`][crate::ConnStatus] of an association.
+    pub fn set_maxseg(&self, assoc_id: AssociationId, maxseg: u32) -> std::io::Result<()> {
+        sctp_set_maxseg_internal(&self.inner, assoc_id, maxseg)
+    }
+
+    /// Get whether the fragmentation of the user messages is disabled. See Section 8.1.11 of RFC
+    /// 6458.
+    pub fn disable_fragments(&self) -> std::io::Result<bool> {
+        sctp_get_disable_fragments_internal(&self.inner)
+    }
+
+    /// Disable (or enable) the fragmentation of the user messages. See Section 8.1.11 of RFC 6458.
+    ///
+    /// When the fragmentation is disabled, a message is either sent in a single `DATA` chunk or
+    /// the send fails with the `EMSGSIZE` OS error, if the message is larger than the
+    /// fragmentation point (the `fragmentation_pt` of the [`ConnStatus`][crate::ConnStatus]). The
+    /// fragmentation point is determined by the Path MTU or by the
+    /// [`set_maxseg`][Self::set_maxseg] value if smaller.
+    pub fn set_disable_fragments(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_disable_fragments_internal(&self.inner, on)
+    }
+
+    /// Get whether the Nagle like algorithm is disabled (`SCTP_NODELAY`). See Section 8.1.5 of
+    /// RFC 6458.
+    pub fn nodelay(&self) -> std::io::Result<bool> {
+        sctp_get_nodelay_internal(&self.inner)
+    }
+
+    /// Disable (or enable) the Nagle like algorithm (`SCTP_NODELAY`).
+    ///
+    /// See [`Socket::set_nodelay`][crate::Socket::set_nodelay] for details.
+    pub fn set_nodelay(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_nodelay_internal(&self.inner, on)
+    }
+
+    /// Get the default PR-SCTP (RFC 3758) time to live of the messages sent on the association.
+    ///
+    /// See [`Socket::default_pr_ttl`][crate::Socket::default_pr_ttl] for details.
+    pub fn default_pr_ttl(
+        &self,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<Option<std::time::Duration>> {
+        sctp_get_default_pr_ttl_internal(&self.inner, assoc_id)
+    }
+
+    /// Configure the socket for a reliable, but bounded latency delivery.
+    ///
+    /// See [`Socket::configure_bounded_latency`][crate::Socket::configure_bounded_latency] for
+    /// details.
+    pub fn configure_bounded_latency(
+        &self,
+        max_latency: std::time::Duration,
+    ) -> std::io::Result<()> {
+        sctp_configure_bounded_latency_internal(&self.inner, max_latency)
+    }
+
+    /// Set the Adaptation Layer Indication for the associations set up after this call.
+    ///
+    /// See [`Socket::set_adaptation_indication`][crate::Socket::set_adaptation_indication] for
+    /// details.
+    pub fn set_adaptation_indication(&self, ind: u32) -> std::io::Result<()> {
+        sctp_set_adaptation_indication_internal(&self.inner, ind)
+    }
+
+    /// Get whether User Message Interleaving (RFC 8260) is actually negotiated on the association.
+    ///
+    /// Interleaving is used only when enabled on both the ends (See
+    /// [`Socket::set_interleaving`][crate::Socket::set_interleaving]), this allows verifying that
+    /// the peer agreed to it.
+    pub fn interleaving_active(&self, assoc_id: AssociationId) -> std::io::Result<bool> {
+        sctp_interleaving_active_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// Note: On Linux, the kernel doubles the size set using
+    /// [`set_recv_buffer_size`][Self::set_recv_buffer_size] (to account for the bookkeeping
+    /// overhead), and the doubled size is returned here.
+    pub fn recv_buffer_size(&self) -> std::io::Result<usize> {
+        get_recv_buffer_size_internal(&self.inner)
+    }
+
+    /// Set the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// The size is capped by the kernel to `net.core.rmem_max`. See
+    /// [`recv_buffer_size`][Self::recv_buffer_size] for the Linux quirk when reading back the size.
+    pub fn set_recv_buffer_size(&self, size: usize) -> std::io::Result<()> {
+        set_recv_buffer_size_internal(&self.inner, size)
+    }
+
+    /// Get the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// Note: On Linux, the kernel doubles the size set using
+    /// [`set_send_buffer_size`][Self::set_send_buffer_size] (to account for the bookkeeping
+    /// overhead), and the doubled size is returned here.
+    pub fn send_buffer_size(&self) -> std::io::Result<usize> {
+        get_send_buffer_size_internal(&self.inner)
+    }
+
+    /// Set the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// The size is capped by the kernel to `net.core.wmem_max`. See
+    /// [`send_buffer_size`][Self::send_buffer_size] for the Linux quirk when reading back the size.
+    pub fn set_send_buffer_size(&self, size: usize) -> std::io::Result<()> {
+        set_send_buffer_size_internal(&self.inner, size)
+    }
+
+    /// Get the time (in seconds) after which the idle associations are closed automatically.
+    /// See Section 8.1.8 of RFC 6458.
+    ///
+    /// Valid only for the One to Many sockets, see [`set_autoclose`][Self::set_autoclose].
+    pub fn autoclose(&self) -> std::io::Result<u32> {
+        sctp_get_autoclose_internal(&self.inner)
+    }
+
+    /// Set the time (in seconds) after which the idle associations are closed automatically.
+    /// See Section 8.1.8 of RFC 6458.
+    ///
+    /// A value of `0` disables the automatic close. Valid only for the One to Many sockets, on a
+    /// One to One socket an [`SctpError::OneToManyOnly`][crate::SctpError::OneToManyOnly] error is
+    /// returned.
+    pub fn set_autoclose(&self, secs: u32) -> std::io::Result<()> {
+        sctp_set_autoclose_internal(&self.inner, secs)
+    }
+
+    /// Set a shared key used for authenticating chunks. See Section 8.3.2 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` sets the key for the future associations on the socket.
+    pub fn set_shared_key(
+        &self,
+        assoc_id: AssociationId,
+        key_id: u16,
+        key: &[u8],
+    ) -> std::io::Result<()> {
+        sctp_set_shared_key_internal(&self.inner, assoc_id, key_id, key)?;
+        self.auth_keys.insert(assoc_id, key_id);
+        Ok(())
+    }
+
+    /// Make the shared key with `key_id` the active key for sending. See Section 8.3.3 of RFC
+    /// 6458.
+    ///
+    /// A different key may be used for a particular message using the
+    /// [`auth_key_id`][crate::SendData::auth_key_id] of the [`SendData`][crate::SendData].
+    pub fn activate_key(&self, assoc_id: AssociationId, key_id: u16) -> std::io::Result<()> {
+        sctp_activate_key_internal(&self.inner, assoc_id, key_id)
+    }
+
+    /// Get the active and the installed shared keys for the association. See Section 8.3 of RFC
+    /// 6458.
+    ///
+    /// The kernel does not report the installed keys, see [`AuthKeyState::installed`] for how
+    /// these are determined.
+    pub fn auth_key_state(&self, assoc_id: AssociationId) -> std::io::Result<AuthKeyState> {
+        sctp_auth_key_state_internal(&self.inner, &self.auth_keys, assoc_id)
+    }
+
+    /// Request that the chunks of the `chunk_type` are received authenticated. See Section 8.3.1
+    /// of RFC 6458.
+    ///
+    /// This applies only to the associations set up after the call.
+    pub fn set_auth_chunk(&self, chunk_type: u8) -> std::io::Result<()> {
+        sctp_set_auth_chunk_internal(&self.inner, chunk_type)
+    }
+
+    /// Set the remote UDP encapsulation port for SCTP over UDP. See RFC 6951.
+    ///
+    /// A `port` of `0` disables the encapsulation. An unspecified `addr` (eg. `0.0.0.0:0`) applies
+    /// the port to all the paths of the association, with an `assoc_id` of `0` this sets the
+    /// default for the future associations (this can be done before `connect`). Otherwise the
+    /// port is set only for the path to `addr`.
+    #[cfg(target_os = "linux")]
+    pub fn set_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        port: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_udp_encaps_port_internal(&self.inner, assoc_id, addr, port)
+    }
+
+    /// Get the priority (`SO_PRIORITY`) of the socket.
+    #[cfg(target_os = "linux")]
+    pub fn priority(&self) -> std::io::Result<u32> {
+        get_priority_internal(&self.inner)
+    }
+
+    /// Set the priority (`SO_PRIORITY`) of the socket.
+    ///
+    /// The priority is used by the Linux queueing disciplines for scheduling the egress packets.
+    /// Setting a priority outside of `0` to `6` requires the `CAP_NET_ADMIN` capability.
+    #[cfg(target_os = "linux")]
+    pub fn set_priority(&self, prio: u32) -> std::io::Result<()> {
+        set_priority_internal(&self.inner, prio)
+    }
 }
 
 impl Drop for Listener {
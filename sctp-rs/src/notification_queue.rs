@@ -0,0 +1,147 @@
+//! Receiving the Notifications and the Data from separate queues.
+
+use tokio::io::unix::AsyncFd;
+
+use std::collections::VecDeque;
+use std::os::unix::io::RawFd;
+
+use crate::internal::sctp_recvmsg_reassembled_internal;
+use crate::types::internal::{Reassembly, ReceiveState};
+use crate::{Notification, NotificationOrData, ReceivedData};
+
+/// Default limit on the number of the queued Notifications.
+const DEFAULT_NOTIFICATION_QUEUE_LIMIT: usize = 64;
+
+/// What to do when a Notification is received while the Notification queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QueueFullPolicy {
+    /// Stop receiving on the socket until the Notifications are drained. The
+    /// [`next_data`][NotificationQueue::next_data] returns an error of the kind
+    /// [`WouldBlock`][std::io::ErrorKind::WouldBlock] while the queue is full.
+    Backpressure,
+
+    /// Drop the oldest queued Notification to make room for the new one.
+    DropOldest,
+}
+
+/// An adaptor that receives on a socket and queues the Notifications and the Data separately.
+///
+/// This structure is created by
+/// [`ConnectedSocket::notification_queue`][crate::ConnectedSocket::notification_queue] or
+/// [`OneToOneSocket::notification_queue`][crate::OneToOneSocket::notification_queue]. It allows
+/// processing the Notifications at a different cadence than the Data. Both
+/// [`next_notification`][Self::next_notification] and [`next_data`][Self::next_data] receive on
+/// the same socket, whatever is received that is not asked for, is queued for the other. The
+/// messages are received the same way as by the `sctp_recv` of the socket.
+///
+/// The Notification queue is bounded (by default to 64 Notifications, see
+/// [`set_notification_queue_limit`][Self::set_notification_queue_limit]), the Data queue is not
+/// bounded, since the Data is never dropped.
+#[derive(Debug)]
+pub struct NotificationQueue<'a> {
+    inner: &'a AsyncFd<RawFd>,
+    state: &'a ReceiveState,
+    notifications: VecDeque<Notification>,
+    data: VecDeque<ReceivedData>,
+    limit: usize,
+    policy: QueueFullPolicy,
+}
+
+impl<'a> NotificationQueue<'a> {
+    pub(crate) fn new(inner: &'a AsyncFd<RawFd>, state: &'a ReceiveState) -> Self {
+        Self {
+            inner,
+            state,
+            notifications: VecDeque::new(),
+            data: VecDeque::new(),
+            limit: DEFAULT_NOTIFICATION_QUEUE_LIMIT,
+            policy: QueueFullPolicy::Backpressure,
+        }
+    }
+
+    /// Set the maximum number of the queued Notifications.
+    ///
+    /// When the limit is lowered below the number of the already queued Notifications, the excess
+    /// Notifications are dropped only with the [`DropOldest`][QueueFullPolicy::DropOldest] policy
+    /// and only when the next Notification is received.
+    pub fn set_notification_queue_limit(&mut self, n: usize) {
+        self.limit = n;
+    }
+
+    /// Set what is done when the Notification queue is full. The default is
+    /// [`Backpressure`][QueueFullPolicy::Backpressure].
+    pub fn set_queue_full_policy(&mut self, policy: QueueFullPolicy) {
+        self.policy = policy;
+    }
+
+    /// Number of the currently queued Notifications.
+    pub fn queued_notifications(&self) -> usize {
+        self.notifications.len()
+    }
+
+    /// Number of the currently queued Data messages.
+    pub fn queued_data(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the next Notification.
+    ///
+    /// The queued Notification is returned if any, otherwise receives on the socket (queueing any
+    /// Data received) until a Notification is received.
+    pub async fn next_notification(&mut self) -> std::io::Result<Notification> {
+        if let Some(notification) = self.notifications.pop_front() {
+            return Ok(notification);
+        }
+
+        loop {
+            match sctp_recvmsg_reassembled_internal(self.inner, self.state, Reassembly::Configured)
+                .await?
+            {
+                NotificationOrData::Notification(notification) => return Ok(notification),
+                NotificationOrData::Data(data) => self.data.push_back(data),
+            }
+        }
+    }
+
+    /// Returns the next Data.
+    ///
+    /// The queued Data is returned if any, otherwise receives on the socket (queueing any
+    /// Notifications received) until Data is received. See [`QueueFullPolicy`] for what happens
+    /// when the Notification queue is full.
+    pub async fn next_data(&mut self) -> std::io::Result<ReceivedData> {
+        if let Some(data) = self.data.pop_front() {
+            return Ok(data);
+        }
+
+        loop {
+            if self.policy == QueueFullPolicy::Backpressure
+                && self.notifications.len() >= self.limit
+            {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::WouldBlock,
+                    "Notification queue full, drain using `next_notification`.",
+                ));
+            }
+
+            match sctp_recvmsg_reassembled_internal(self.inner, self.state, Reassembly::Configured)
+                .await?
+            {
+                NotificationOrData::Data(data) => return Ok(data),
+                NotificationOrData::Notification(notification) => {
+                    while self.notifications.len() >= self.limit {
+                        match self.notifications.pop_front() {
+                            Some(dropped) => {
+                                log::debug!("Notification queue full, dropping: {:#?}", dropped)
+                            }
+                            None => break,
+                        }
+                    }
+                    if self.notifications.len() < self.limit {
+                        self.notifications.push_back(notification);
+                    }
+                }
+            }
+        }
+    }
+}
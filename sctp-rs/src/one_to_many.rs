@@ -0,0 +1,339 @@
+//! One to Many (UDP Style) SCTP Socket with any number of associations.
+
+use tokio::io::unix::AsyncFd;
+
+use std::net::SocketAddr;
+use std::os::unix::io::RawFd;
+
+use crate::consts::{SCTP_ABORT, SCTP_EOF};
+#[allow(unused)]
+use crate::internal::*;
+use crate::types::internal::{Reassembly, ReceiveState, SndRcvInfo};
+use crate::{
+    AssociationId, BindxFlags, ConnStatus, Event, NotificationOrData, OneToOneSocket,
+    ReceivedMessageInfo, SendData, SendFlags, SendInfo, SocketToAssociation, SubscribeEventAssocId,
+};
+
+/// A structure representing a listening One to Many (UDP Style) SCTP Socket.
+///
+/// A [`OneToManySocket`] carries any number of associations, each identified by an Association
+/// ID. APIs that are valid only for the One to Many sockets (like
+/// [`sctp_peeloff`][Self::sctp_peeloff] or [`assoc_ids`][Self::assoc_ids]) are available only on
+/// this type, unlike the [`Listener`][crate::Listener] which may be of either style.
+///
+/// Associations with new peers are set up either when a peer connects to this socket or
+/// implicitly when data is sent to a new peer address using [`sctp_send`][Self::sctp_send].
+///
+/// ```rust,compile_fail
+/// # fn status(socket: sctp_rs::OneToManySocket) {
+/// // An Association ID is always required on the `OneToManySocket`.
+/// let _ = socket.sctp_get_status();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OneToManySocket {
+    inner: AsyncFd<RawFd>,
+    state: ReceiveState,
+}
+
+impl OneToManySocket {
+    /// Create a new IPv4 family One to Many socket bound to `addrs` and listening on it.
+    pub fn listen_v4(addrs: &[SocketAddr], backlog: i32) -> std::io::Result<Self> {
+        Self::new_listening(libc::AF_INET, addrs, backlog)
+    }
+
+    /// Create a new IPv6 family One to Many socket bound to `addrs` and listening on it.
+    pub fn listen_v6(addrs: &[SocketAddr], backlog: i32) -> std::io::Result<Self> {
+        Self::new_listening(libc::AF_INET6, addrs, backlog)
+    }
+
+    /// Binds to one or more local addresses. See: Section 9.1 RFC 6458
+    pub fn sctp_bindx(&self, addrs: &[SocketAddr], flags: BindxFlags) -> std::io::Result<()> {
+        sctp_bindx_internal(&self.inner, addrs, flags)
+    }
+
+    /// Peels off an association into a [`OneToOneSocket`]. See: Section 9.2 RFC 6458
+    pub fn sctp_peeloff(&self, assoc_id: AssociationId) -> std::io::Result<OneToOneSocket> {
+        OneToOneSocket::from_rawfd(sctp_peeloff_internal(&self.inner, assoc_id)?)
+    }
+
+    /// Get the Association IDs of all the current associations on this socket.
+    ///
+    /// Uses the `SCTP_GET_ASSOC_ID_LIST` socket option. See Section 8.2.7 RFC 6458.
+    pub fn assoc_ids(&self) -> std::io::Result<Vec<AssociationId>> {
+        sctp_get_assoc_ids_internal(&self.inner)
+    }
+
+    /// Get the Peer Address(es) of all the current associations on this socket.
+    ///
+    /// This combines [`assoc_ids`][Self::assoc_ids] with [`sctp_getpaddrs`][Self::sctp_getpaddrs]
+    /// for each of the associations. Associations that are closed in between are skipped.
+    pub fn all_peer_addrs(&self) -> std::io::Result<Vec<(AssociationId, Vec<SocketAddr>)>> {
+        let mut all = vec![];
+        for assoc_id in self.assoc_ids()? {
+            match self.sctp_getpaddrs(assoc_id) {
+                Ok(addrs) => all.push((assoc_id, addrs)),
+                Err(e) if e.raw_os_error() == Some(libc::EINVAL) => {
+                    log::debug!("Association {} closed, skipping.", assoc_id);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(all)
+    }
+
+    /// Get Peer Address(es) for the given Association ID. See: Section 9.3 RFC 6458
+    pub fn sctp_getpaddrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getpaddrs_internal(&self.inner, assoc_id)
+    }
+
+    /// Get's the Local Addresses for the association. See: Section 9.4 RFC 6458
+    pub fn sctp_getladdrs(&self, assoc_id: AssociationId) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getladdrs_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the local address of the socket.
+    ///
+    /// See [`Socket::local_addr`][crate::Socket::local_addr] for details.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        local_addr_internal(&self.inner)
+    }
+
+    /// Whether the socket is an SCTP socket, ie. its protocol (`SO_PROTOCOL`) is `IPPROTO_SCTP`.
+    ///
+    /// See [`Socket::is_sctp`][crate::Socket::is_sctp] for details.
+    pub fn is_sctp(&self) -> bool {
+        is_sctp_internal(&self.inner)
+    }
+
+    /// Receive Data or Notification from any of the associations on this socket.
+    ///
+    /// Unlike [`ConnectedSocket::sctp_recv`][crate::ConnectedSocket::sctp_recv], the messages
+    /// received in multiple parts are not reassembled, each part is returned as is. Use
+    /// [`recv_into`][Self::recv_into] to tell the last part of a message.
+    pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Configured).await
+    }
+
+    /// Receive Data or Notification into the given buffer.
+    ///
+    /// See [`ConnectedSocket::recv_into`][crate::ConnectedSocket::recv_into] for details.
+    pub async fn recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedMessageInfo> {
+        sctp_recvmsg_into_resubscribe_internal(&self.inner, &self.state, buf).await
+    }
+
+    /// Receive Data or Notification from the socket without waiting.
+    ///
+    /// See [`ConnectedSocket::try_recv`][crate::ConnectedSocket::try_recv] for details.
+    pub fn try_recv(&self) -> std::io::Result<Option<NotificationOrData>> {
+        sctp_try_recvmsg_reassembled_internal(&self.inner, &self.state)
+    }
+
+    /// Set the non-blocking mode of the socket.
+    ///
+    /// See [`ConnectedSocket::set_nonblocking`][crate::ConnectedSocket::set_nonblocking] for
+    /// details.
+    pub fn set_nonblocking(&self, on: bool) -> std::io::Result<()> {
+        set_nonblocking_internal(&self.inner, on)
+    }
+
+    /// Send Data and Anciliary data if any to the given peer address.
+    ///
+    /// When there is no association with `to` yet, a new association is set up by the send. Its
+    /// Association ID can be looked up using [`peer_assoc_id`][Self::peer_assoc_id], without
+    /// waiting for the [`AssociationChange`][crate::AssociationChange] notification.
+    pub async fn sctp_send(&self, to: SocketAddr, data: SendData) -> std::io::Result<()> {
+        sctp_sendmsg_internal(&self.inner, Some(to), data).await
+    }
+
+    /// Get the Association ID of the association with the peer address `addr`.
+    ///
+    /// Returns an error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput] if there
+    /// is no association with `addr`.
+    pub fn peer_assoc_id(&self, addr: SocketAddr) -> std::io::Result<AssociationId> {
+        sctp_peer_addr_assoc_id_internal(&self.inner, addr)
+    }
+
+    /// Send a message to the given peer address, the classic `sctp_sendmsg` (See Section 9.7 of
+    /// RFC 6458).
+    ///
+    /// The message is sent on the stream `sid` with the Payload Protocol Identifier `ppid` and the
+    /// `flags` (eg. [`SendFlags::UNORDERED`]). A non-zero `ttl` is the time to live of the message in
+    /// milliseconds, after which the message is abandoned if not yet sent (this requires PR-SCTP
+    /// support of the peer). When there is no association with `to` yet, a new association is set
+    /// up by the send. An address of a family that cannot be used on this socket returns an error
+    /// wrapping [`SctpError::AddressFamilyMismatch`][crate::SctpError::AddressFamilyMismatch].
+    ///
+    /// Returns the number of bytes sent.
+    pub async fn sctp_sendmsg(
+        &self,
+        payload: &[u8],
+        to: SocketAddr,
+        sid: u16,
+        ppid: u32,
+        flags: SendFlags,
+        ttl: u32,
+    ) -> std::io::Result<usize> {
+        let snd_rcv_info = SndRcvInfo {
+            stream: sid,
+            flags: flags.bits(),
+            ppid,
+            timetolive: ttl,
+            ..Default::default()
+        };
+        sctp_sendmsg_to_internal(&self.inner, payload, to, snd_rcv_info).await
+    }
+
+    /// Abort the given association with a user initiated abort reason.
+    ///
+    /// See [`Listener::abort`][crate::Listener::abort] for details.
+    pub async fn abort(&self, assoc_id: AssociationId, reason: &[u8]) -> std::io::Result<()> {
+        sctp_send_flags_internal(&self.inner, assoc_id, SCTP_ABORT, reason).await
+    }
+
+    /// Gracefully shutdown the given association.
+    ///
+    /// See [`Listener::shutdown_assoc`][crate::Listener::shutdown_assoc] for details.
+    pub async fn shutdown_assoc(&self, assoc_id: AssociationId) -> std::io::Result<()> {
+        sctp_send_flags_internal(&self.inner, assoc_id, SCTP_EOF, &[]).await
+    }
+
+    /// Subscribe to SCTP Events for the given association(s). See section 6.2.1 of RFC6458.
+    pub fn sctp_subscribe_events(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> std::io::Result<()> {
+        let mut failures = vec![];
+        for ev in events {
+            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, true);
+            if result.is_err() {
+                failures.push(result.err().unwrap());
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(std::io::Error::other(format!("{:?}", failures)))
+        }
+    }
+
+    /// Subscribe to the `events` again, whenever an association is restarted.
+    ///
+    /// See [`ConnectedSocket::set_resubscribe_on_restart`][crate::ConnectedSocket::set_resubscribe_on_restart]
+    /// for details.
+    pub fn set_resubscribe_on_restart(&self, events: &[Event]) {
+        self.state.resubscribe.set(events);
+    }
+
+    /// Unsubscribe from SCTP Events for the given association(s). See section 6.2.1 of RFC6458.
+    pub fn sctp_unsubscribe_events(
+        &self,
+        events: &[Event],
+        assoc_id: SubscribeEventAssocId,
+    ) -> std::io::Result<()> {
+        let mut failures = vec![];
+        for ev in events {
+            let result = sctp_subscribe_event_internal(&self.inner, ev.clone(), assoc_id, false);
+            if result.is_err() {
+                failures.push(result.err().unwrap());
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(std::io::Error::other(format!("{:?}", failures)))
+        }
+    }
+
+    /// Setup parameters for new associations. See Section 8.1.3 of RFC 6458.
+    pub fn sctp_setup_init_params(
+        &self,
+        ostreams: u16,
+        istreams: u16,
+        retries: u16,
+        timeout: u16,
+    ) -> std::io::Result<()> {
+        sctp_setup_init_params_internal(&self.inner, ostreams, istreams, retries, timeout)
+    }
+
+    /// Request to receive `RcvInfo` ancillary data.
+    pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
+        request_rcvinfo_internal(&self.inner, on)
+    }
+
+    /// Request to receive `NxtInfo` ancillary data.
+    pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
+        request_nxtinfo_internal(&self.inner, on)
+    }
+
+    /// Get the status of the given association.
+    pub fn sctp_get_status(&self, assoc_id: AssociationId) -> std::io::Result<ConnStatus> {
+        sctp_get_status_internal(&self.inner, assoc_id)
+    }
+
+    /// Set Default `SendInfo` values for the given association.
+    pub fn set_default_sndinfo(
+        &self,
+        info: SendInfo,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<()> {
+        sctp_set_default_sendinfo_internal(&self.inner, SendInfo { assoc_id, ..info })
+    }
+
+    /// Get the Default `SendInfo` values for the given association.
+    pub fn get_default_sndinfo(&self, assoc_id: AssociationId) -> std::io::Result<SendInfo> {
+        sctp_get_default_sendinfo_internal(&self.inner, assoc_id)
+    }
+
+    /// Get the time (in seconds) after which the idle associations are closed automatically.
+    /// See Section 8.1.8 of RFC 6458.
+    pub fn autoclose(&self) -> std::io::Result<u32> {
+        sctp_get_autoclose_internal(&self.inner)
+    }
+
+    /// Set the time (in seconds) after which the idle associations are closed automatically.
+    /// See Section 8.1.8 of RFC 6458.
+    ///
+    /// A value of `0` disables the automatic close.
+    pub fn set_autoclose(&self, secs: u32) -> std::io::Result<()> {
+        sctp_set_autoclose_internal(&self.inner, secs)
+    }
+
+    /// Set the Adaptation Layer Indication for the associations set up after this call.
+    ///
+    /// See [`Socket::set_adaptation_indication`][crate::Socket::set_adaptation_indication] for
+    /// details.
+    pub fn set_adaptation_indication(&self, ind: u32) -> std::io::Result<()> {
+        sctp_set_adaptation_indication_internal(&self.inner, ind)
+    }
+
+    // functions not part of public APIs
+    fn new_listening(
+        domain: libc::c_int,
+        addrs: &[SocketAddr],
+        backlog: i32,
+    ) -> std::io::Result<Self> {
+        let this = Self {
+            inner: AsyncFd::new(sctp_socket_internal(
+                domain,
+                SocketToAssociation::OneToMany,
+            )?)?,
+            state: ReceiveState::without_reassembly(),
+        };
+        sctp_bindx_internal(&this.inner, addrs, BindxFlags::Add)?;
+        listen_internal(&this.inner, backlog)?;
+
+        Ok(this)
+    }
+}
+
+impl Drop for OneToManySocket {
+    // Drop for `OneToManySocket`. We close the `inner` RawFd
+    fn drop(&mut self) {
+        close_internal(&self.inner);
+    }
+}
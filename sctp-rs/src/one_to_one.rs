@@ -0,0 +1,247 @@
+//! One to One (TCP Style) SCTP Socket with a single association.
+
+use tokio::io::unix::AsyncFd;
+
+use std::net::SocketAddr;
+use std::os::unix::io::RawFd;
+
+#[allow(unused)]
+use crate::internal::*;
+use crate::types::internal::{Reassembly, ReceiveState};
+use crate::{
+    ConnStatus, Event, Incoming, NotificationOrData, NotificationQueue, ReceivedMessageInfo,
+    SendData, SendInfo, SocketToAssociation, SubscribeEventAssocId,
+};
+
+/// A structure representing a connected One to One (TCP Style) SCTP Socket.
+///
+/// Unlike the [`ConnectedSocket`][crate::ConnectedSocket], a [`OneToOneSocket`] is always
+/// associated with exactly one peer association. Hence none of the APIs on this socket take an
+/// Association ID and the APIs that are valid only for One to Many sockets (like `sctp_peeloff`)
+/// are not available at all.
+///
+/// A [`OneToOneSocket`] is created either by [`connect`][Self::connect]ing to a peer or by
+/// [`peeling off`][crate::OneToManySocket::sctp_peeloff] an association from a
+/// [`OneToManySocket`][crate::OneToManySocket].
+///
+/// ```rust,compile_fail
+/// # async fn peeloff(socket: sctp_rs::OneToOneSocket) {
+/// // One to Many only APIs are not available on the `OneToOneSocket`.
+/// let _ = socket.sctp_peeloff(1);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct OneToOneSocket {
+    inner: AsyncFd<RawFd>,
+    state: ReceiveState,
+}
+
+impl OneToOneSocket {
+    /// Connect to a (possibly multi-homed) Peer. See Section 9.9 RFC 6458
+    ///
+    /// An IPv6 family socket is created if any of the `addrs` is an IPv6 address, otherwise an
+    /// IPv4 family socket is created.
+    pub async fn connect(addrs: &[SocketAddr]) -> std::io::Result<Self> {
+        let domain = if addrs.iter().any(|addr| addr.is_ipv6()) {
+            libc::AF_INET6
+        } else {
+            libc::AF_INET
+        };
+        let fd = AsyncFd::new(sctp_socket_internal(domain, SocketToAssociation::OneToOne)?)?;
+
+        let (rawfd, _) = sctp_connectx_internal(fd, addrs).await?;
+        Self::from_rawfd(rawfd)
+    }
+
+    /// Perform a TCP like half close.
+    ///
+    /// Note: however that the semantics for TCP and SCTP half close are different. See section
+    /// 4.1.7 of RFC 6458 for details.
+    pub fn shutdown(&self, how: std::net::Shutdown) -> std::io::Result<()> {
+        shutdown_internal(&self.inner, how)
+    }
+
+    /// Get Peer addresses for the association. See Section 9.3 RFC 6458.
+    pub fn sctp_getpaddrs(&self) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getpaddrs_internal(&self.inner, 0.into())
+    }
+
+    /// Get Local addresses for the association. See section 9.5 RFC 6458.
+    pub fn sctp_getladdrs(&self) -> std::io::Result<Vec<SocketAddr>> {
+        sctp_getladdrs_internal(&self.inner, 0.into())
+    }
+
+    /// Get the local address of the socket.
+    ///
+    /// See [`Socket::local_addr`][crate::Socket::local_addr] for details.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        local_addr_internal(&self.inner)
+    }
+
+    /// Whether the socket is an SCTP socket, ie. its protocol (`SO_PROTOCOL`) is `IPPROTO_SCTP`.
+    ///
+    /// See [`Socket::is_sctp`][crate::Socket::is_sctp] for details.
+    pub fn is_sctp(&self) -> bool {
+        is_sctp_internal(&self.inner)
+    }
+
+    /// Receive Data or Notification from the socket.
+    ///
+    /// See [`ConnectedSocket::sctp_recv`][crate::ConnectedSocket::sctp_recv] for details, except
+    /// that the messages received in multiple parts are not reassembled. Use
+    /// [`recv_into`][Self::recv_into] to tell the last part of a message.
+    pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Configured).await
+    }
+
+    /// Receive Data or Notification into the given buffer.
+    ///
+    /// See [`ConnectedSocket::recv_into`][crate::ConnectedSocket::recv_into] for details.
+    pub async fn recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedMessageInfo> {
+        sctp_recvmsg_into_resubscribe_internal(&self.inner, &self.state, buf).await
+    }
+
+    /// Receive Data or Notification from the socket without waiting.
+    ///
+    /// See [`ConnectedSocket::try_recv`][crate::ConnectedSocket::try_recv] for details.
+    pub fn try_recv(&self) -> std::io::Result<Option<NotificationOrData>> {
+        sctp_try_recvmsg_reassembled_internal(&self.inner, &self.state)
+    }
+
+    /// Set the non-blocking mode of the socket.
+    ///
+    /// See [`ConnectedSocket::set_nonblocking`][crate::ConnectedSocket::set_nonblocking] for
+    /// details.
+    pub fn set_nonblocking(&self, on: bool) -> std::io::Result<()> {
+        set_nonblocking_internal(&self.inner, on)
+    }
+
+    /// Receive a Notification or a complete Data message of at most `max` bytes.
+    ///
+    /// A message larger than the receive buffer is received in multiple parts, which are
+    /// reassembled here until the end of the message (`MSG_EOR`), even if the reassembly is turned
+    /// off for the socket. If the message is larger than `max` bytes, it is discarded and an error
+    /// wrapping [`SctpError::MessageTooLarge`][crate::SctpError::MessageTooLarge] is returned,
+    /// this protects against the peer sending unbounded messages. The Notifications received
+    /// while waiting for the rest of a message are returned right away, the parts received so far
+    /// are kept for the next call.
+    pub async fn recv_message_bounded(&self, max: usize) -> std::io::Result<NotificationOrData> {
+        sctp_recvmsg_reassembled_internal(&self.inner, &self.state, Reassembly::Bounded(max)).await
+    }
+
+    /// Returns an [`Incoming`] adaptor to receive on this socket until the association is closed.
+    ///
+    /// See [`Incoming::next`] for details.
+    pub fn incoming(&self) -> Incoming<'_> {
+        Incoming::new(&self.inner, &self.state)
+    }
+
+    /// Returns a [`NotificationQueue`] adaptor to receive the Notifications and the Data
+    /// separately on this socket.
+    pub fn notification_queue(&self) -> NotificationQueue<'_> {
+        NotificationQueue::new(&self.inner, &self.state)
+    }
+
+    /// Send Data and Anciliary data if any on the association.
+    pub async fn sctp_send(&self, data: SendData) -> std::io::Result<()> {
+        sctp_sendmsg_internal(&self.inner, None, data).await
+    }
+
+    /// Subscribe to SCTP Events for the association. See section 6.2.1 of RFC6458.
+    pub fn sctp_subscribe_events(&self, events: &[Event]) -> std::io::Result<()> {
+        let mut failures = vec![];
+        for ev in events {
+            let result = sctp_subscribe_event_internal(
+                &self.inner,
+                ev.clone(),
+                SubscribeEventAssocId::Future,
+                true,
+            );
+            if result.is_err() {
+                failures.push(result.err().unwrap());
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(std::io::Error::other(format!("{:?}", failures)))
+        }
+    }
+
+    /// Subscribe to the `events` again, whenever the association is restarted.
+    ///
+    /// See [`ConnectedSocket::set_resubscribe_on_restart`][crate::ConnectedSocket::set_resubscribe_on_restart]
+    /// for details.
+    pub fn set_resubscribe_on_restart(&self, events: &[Event]) {
+        self.state.resubscribe.set(events);
+    }
+
+    /// Unsubscribe from SCTP Events for the association. See section 6.2.1 of RFC6458.
+    pub fn sctp_unsubscribe_events(&self, events: &[Event]) -> std::io::Result<()> {
+        let mut failures = vec![];
+        for ev in events {
+            let result = sctp_subscribe_event_internal(
+                &self.inner,
+                ev.clone(),
+                SubscribeEventAssocId::Future,
+                false,
+            );
+            if result.is_err() {
+                failures.push(result.err().unwrap());
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(std::io::Error::other(format!("{:?}", failures)))
+        }
+    }
+
+    /// Request to receive `RcvInfo` ancillary data.
+    pub fn sctp_request_rcvinfo(&self, on: bool) -> std::io::Result<()> {
+        request_rcvinfo_internal(&self.inner, on)
+    }
+
+    /// Request to receive `NxtInfo` ancillary data.
+    pub fn sctp_request_nxtinfo(&self, on: bool) -> std::io::Result<()> {
+        request_nxtinfo_internal(&self.inner, on)
+    }
+
+    /// Get the status of the association.
+    pub fn sctp_get_status(&self) -> std::io::Result<ConnStatus> {
+        sctp_get_status_internal(&self.inner, 0.into())
+    }
+
+    /// Set Default `SendInfo` values for the association.
+    pub fn set_default_sndinfo(&self, info: SendInfo) -> std::io::Result<()> {
+        sctp_set_default_sendinfo_internal(
+            &self.inner,
+            SendInfo {
+                assoc_id: 0.into(),
+                ..info
+            },
+        )
+    }
+
+    /// Get the Default `SendInfo` values for the association.
+    pub fn get_default_sndinfo(&self) -> std::io::Result<SendInfo> {
+        sctp_get_default_sendinfo_internal(&self.inner, 0.into())
+    }
+
+    // functions not part of public APIs
+    pub(crate) fn from_rawfd(rawfd: RawFd) -> std::io::Result<Self> {
+        Ok(Self {
+            inner: AsyncFd::new(rawfd)?,
+            state: ReceiveState::without_reassembly(),
+        })
+    }
+}
+
+impl Drop for OneToOneSocket {
+    // Drop for `OneToOneSocket`. We close the `inner` RawFd
+    fn drop(&mut self) {
+        close_internal(&self.inner);
+    }
+}
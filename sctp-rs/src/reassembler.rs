@@ -0,0 +1,170 @@
+//! Reassembly of the Data messages received in multiple parts.
+
+use std::collections::HashMap;
+
+use crate::{
+    AssocChangeState, AssociationChange, AssociationId, Notification, ReceivedData, SctpError,
+};
+
+/// Default maximum size of a reassembled message.
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 1 << 20;
+
+// A message being reassembled. The `message` is dropped once the message grows larger than the
+// maximum size, the rest of its parts are only counted in `size`.
+#[derive(Debug, Clone)]
+struct PartialMessage {
+    message: Option<ReceivedData>,
+    size: usize,
+}
+
+/// A helper to coalesce the parts of the Data messages, that are received in multiple parts, into
+/// complete messages.
+///
+/// A message larger than the receive buffer (or delivered using the partial delivery API) is
+/// received in multiple parts, only the last part has the end of the record (`MSG_EOR`) set. With
+/// the fragment interleaving (See [`set_interleaving`][crate::Socket::set_interleaving]), the parts
+/// of the messages on different streams may be received interleaved, so the parts are buffered per
+/// association and stream. This requires the [`RcvInfo`][crate::RcvInfo] of the received messages
+/// (See [`sctp_request_rcvinfo`][crate::ConnectedSocket::sctp_request_rcvinfo]), without it all
+/// the parts are assumed to belong to a single message.
+///
+/// The buffered parts are discarded when the partial delivery of the message is aborted (See
+/// [`PdapiEvent`][crate::PdapiEvent]) or when the association is lost or restarted, for this every
+/// Notification received should be passed to [`notification`][Self::notification].
+///
+/// The size of a message is limited (by default to 1 MiB, see
+/// [`set_max_message_size`][Self::set_max_message_size]), this protects against the peer sending
+/// unbounded messages.
+#[derive(Debug, Clone)]
+pub struct MessageReassembler {
+    // The messages without the `RcvInfo` are buffered with the `None` key.
+    partial: HashMap<Option<(AssociationId, u16)>, PartialMessage>,
+    max_message_size: usize,
+}
+
+impl Default for MessageReassembler {
+    fn default() -> Self {
+        Self {
+            partial: HashMap::new(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+impl MessageReassembler {
+    /// Create a new reassembler, that has no buffered parts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum size of a reassembled message.
+    ///
+    /// The buffered parts of a message are discarded as soon as the message grows larger than
+    /// `max`, the rest of its parts are discarded as they are received.
+    pub fn set_max_message_size(&mut self, max: usize) {
+        self.max_message_size = max;
+    }
+
+    pub(crate) fn max_message_size(&self) -> usize {
+        self.max_message_size
+    }
+
+    /// Add a received part of a message, `eor` tells whether it is the last part.
+    ///
+    /// Returns the complete message when the last part is received. The ancillary information of
+    /// the complete message is the one of its first part, except the `nxt_info` which is the one
+    /// of the last part. When the last part of a message larger than the maximum size is received,
+    /// [`SctpError::MessageTooLarge`] is returned.
+    pub fn push(
+        &mut self,
+        data: ReceivedData,
+        eor: bool,
+    ) -> Result<Option<ReceivedData>, SctpError> {
+        let key = Self::key(&data);
+        let mut partial = self.partial.remove(&key).unwrap_or(PartialMessage {
+            message: None,
+            size: 0,
+        });
+
+        let first = partial.size == 0;
+        partial.size += data.payload.len();
+        if partial.size > self.max_message_size {
+            partial.message = None;
+        } else if first {
+            partial.message = Some(data);
+        } else if let Some(message) = partial.message.as_mut() {
+            message.payload.extend(data.payload);
+            message.nxt_info = data.nxt_info;
+        }
+
+        if !eor {
+            self.partial.insert(key, partial);
+            return Ok(None);
+        }
+
+        match partial.message {
+            Some(message) if partial.size <= self.max_message_size => Ok(Some(message)),
+            _ => Err(SctpError::MessageTooLarge {
+                size: partial.size,
+                max: self.max_message_size,
+            }),
+        }
+    }
+
+    /// Update the reassembler with a received Notification.
+    ///
+    /// The buffered parts of a message whose partial delivery is aborted are discarded, as are all
+    /// the buffered parts of an association that is lost or restarted.
+    pub fn notification(&mut self, notification: &Notification) {
+        match notification {
+            Notification::PartialDeliveryEvent(pdapi) if pdapi.is_aborted() => match pdapi.sid() {
+                Ok(sid) => self.reset(pdapi.assoc_id, sid),
+                Err(e) => log::error!("Error: {} in the partial delivery event: {}", e, pdapi),
+            },
+            Notification::AssociationChange(AssociationChange {
+                state:
+                    AssocChangeState::CommLost
+                    | AssocChangeState::Restart
+                    | AssocChangeState::ShutdownComplete,
+                assoc_id,
+                ..
+            }) => {
+                let assoc_id = *assoc_id;
+                self.partial
+                    .retain(|key, _| key.is_some_and(|(id, _)| id != assoc_id));
+            }
+            _ => {}
+        }
+    }
+
+    /// Discard the buffered parts of the message on the given association and stream.
+    ///
+    /// The parts buffered without the [`RcvInfo`][crate::RcvInfo] are discarded as well.
+    pub fn reset(&mut self, assoc_id: AssociationId, stream: u16) {
+        if let Some(dropped) = self.partial.remove(&Some((assoc_id, stream))) {
+            log::debug!(
+                "Discarding {} bytes of a partial message on association: {}, stream: {}",
+                dropped.size,
+                assoc_id,
+                stream
+            );
+        }
+        if let Some(dropped) = self.partial.remove(&None) {
+            log::debug!("Discarding {} bytes of a partial message.", dropped.size);
+        }
+    }
+
+    /// Number of the bytes buffered for the message on the given association and stream.
+    pub fn pending(&self, assoc_id: AssociationId, stream: u16) -> usize {
+        self.partial
+            .get(&Some((assoc_id, stream)))
+            .and_then(|partial| partial.message.as_ref())
+            .map_or(0, |message| message.payload.len())
+    }
+
+    fn key(data: &ReceivedData) -> Option<(AssociationId, u16)> {
+        data.rcv_info
+            .as_ref()
+            .map(|rcv_info| (rcv_info.assoc_id, rcv_info.sid))
+    }
+}
@@ -5,8 +5,10 @@ use std::os::unix::io::RawFd;
 
 use tokio::io::unix::AsyncFd;
 
+use crate::types::internal::AuthKeys;
 use crate::{
-    AssociationId, BindxFlags, ConnStatus, ConnectedSocket, Event, Listener, SocketToAssociation,
+    AssocInfo, AssociationChange, AssociationId, AuthKeyState, BindxFlags, ConnStatus,
+    ConnectedSocket, Event, HandshakeParams, Listener, RtoInfo, SocketToAssociation,
     SubscribeEventAssocId,
 };
 
@@ -20,6 +22,7 @@ use super::internal::*;
 /// associations, we are calling it a 'connected' socket).
 pub struct Socket {
     inner: AsyncFd<RawFd>,
+    auth_keys: AuthKeys,
 }
 
 impl Socket {
@@ -34,6 +37,7 @@ impl Socket {
     pub fn new_v4(assoc: SocketToAssociation) -> std::io::Result<Self> {
         Ok(Self {
             inner: AsyncFd::new(sctp_socket_internal(libc::AF_INET, assoc)?)?,
+            auth_keys: AuthKeys::default(),
         })
     }
 
@@ -47,6 +51,7 @@ impl Socket {
     pub fn new_v6(assoc: SocketToAssociation) -> std::io::Result<Self> {
         Ok(Self {
             inner: AsyncFd::new(sctp_socket_internal(libc::AF_INET6, assoc)?)?,
+            auth_keys: AuthKeys::default(),
         })
     }
 
@@ -59,12 +64,30 @@ impl Socket {
         self.sctp_bindx(&[addr], BindxFlags::Add)
     }
 
+    /// Get the local address of the socket.
+    ///
+    /// This is useful to learn the port picked by the kernel, when bound to the port `0`. For a
+    /// socket bound to multiple addresses, the primary (first bound) address is returned, use
+    /// `sctp_getladdrs` on the connected or listening socket for all the local addresses.
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        local_addr_internal(&self.inner)
+    }
+
+    /// Whether the socket is an SCTP socket, ie. its protocol (`SO_PROTOCOL`) is `IPPROTO_SCTP`.
+    ///
+    /// This is useful to validate a socket obtained from elsewhere (eg. passed by another
+    /// process), see
+    /// [`ConnectedSocket::from_raw_fd_checked`][crate::ConnectedSocket::from_raw_fd_checked].
+    pub fn is_sctp(&self) -> bool {
+        is_sctp_internal(&self.inner)
+    }
+
     /// Listen on a given socket.
     ///
     /// This successful operation  returns [`Listener`] consuming this structure. The `backlog`
     /// parameter determines the length of the listen queue.
     pub fn listen(self, backlog: i32) -> std::io::Result<Listener> {
-        sctp_listen_internal(self.inner, backlog)
+        Ok(sctp_listen_internal(self.inner, backlog)?.with_auth_keys(self.auth_keys))
     }
 
     /// Connect to SCTP Server.
@@ -74,7 +97,39 @@ impl Socket {
         self,
         addr: SocketAddr,
     ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
-        sctp_connectx_internal(self.inner, &[addr]).await
+        let (rawfd, assoc_id) = sctp_connectx_internal(self.inner, &[addr]).await?;
+        let connected = ConnectedSocket::from_rawfd(rawfd)?.with_auth_keys(self.auth_keys);
+        Ok((connected, assoc_id))
+    }
+
+    /// Connect to SCTP Server, waiting until the association is established or the `timeout`
+    /// expires.
+    ///
+    /// Unlike [`connect`][Self::connect], which returns as soon as the handshake is started, this
+    /// returns only once the association is in the `Established` state. If that does not happen
+    /// within the `timeout`, the socket is closed and an error of the kind
+    /// [`TimedOut`][std::io::ErrorKind::TimedOut] is returned. If the association fails to be
+    /// established (eg. the peer aborts it), an error is returned right away. The socket is always
+    /// non-blocking, so the blocking mode of the socket is not changed.
+    pub async fn connect_timeout(
+        self,
+        addr: SocketAddr,
+        timeout: std::time::Duration,
+    ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
+        let (connected, assoc_id) =
+            sctp_connectx_timeout_internal(self.inner, &[addr], timeout).await?;
+        Ok((connected.with_auth_keys(self.auth_keys), assoc_id))
+    }
+
+    // Connect and wait for the `CommUp`, used by the `ClientBuilder`.
+    pub(crate) async fn sctp_connectx_comm_up(
+        self,
+        addrs: &[SocketAddr],
+        timeout: Option<std::time::Duration>,
+    ) -> std::io::Result<(ConnectedSocket, AssociationChange)> {
+        let (connected, change) =
+            sctp_connectx_comm_up_internal(self.inner, addrs, timeout).await?;
+        Ok((connected.with_auth_keys(self.auth_keys), change))
     }
 
     /// SCTP Specific extension for binding to multiple addresses on a given socket. See Section
@@ -100,7 +155,9 @@ impl Socket {
         self,
         addrs: &[SocketAddr],
     ) -> std::io::Result<(ConnectedSocket, AssociationId)> {
-        sctp_connectx_internal(self.inner, addrs).await
+        let (rawfd, assoc_id) = sctp_connectx_internal(self.inner, addrs).await?;
+        let connected = ConnectedSocket::from_rawfd(rawfd)?.with_auth_keys(self.auth_keys);
+        Ok((connected, assoc_id))
     }
 
     /// Subscribe to a given SCTP Event on the given socket. See section 6.2.1 of RFC6458.
@@ -150,10 +207,7 @@ impl Socket {
         if failures.is_empty() {
             Ok(())
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
+            Err(std::io::Error::other(format!("{:?}", failures)))
         }
     }
 
@@ -176,16 +230,25 @@ impl Socket {
         if failures.is_empty() {
             Ok(())
         } else {
-            Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("{:?}", failures),
-            ))
+            Err(std::io::Error::other(format!("{:?}", failures)))
         }
     }
 
+    /// Subscribe to all the SCTP Events that are delivered as notifications.
+    ///
+    /// This is a convenience API mostly useful while debugging, where it is desirable to see the
+    /// full notification stream. All [`Event`]s except [`Event::DataIo`] are subscribed to and
+    /// the first subscription error (if any) is returned.
+    pub fn enable_all_events(&self, assoc_id: SubscribeEventAssocId) -> std::io::Result<()> {
+        sctp_enable_all_events_internal(&self.inner, assoc_id)
+    }
+
     /// Setup parameters for a new association.
     ///
-    /// To specify custom parameters for a new association this API is used.
+    /// To specify custom parameters for a new association this API is used. The parameters are
+    /// used only for the associations set up after this call, hence for the One to One sockets,
+    /// this should be called before `listen` or `connect`. (The associations `accept`ed on a One
+    /// to One [`Listener`][crate::Listener] use the parameters of the [`Listener`][crate::Listener].)
     pub fn sctp_setup_init_params(
         &self,
         ostreams: u16,
@@ -216,4 +279,272 @@ impl Socket {
     pub fn sctp_get_status(&self, assoc_id: AssociationId) -> std::io::Result<ConnStatus> {
         sctp_get_status_internal(&self.inner, assoc_id)
     }
+
+    /// Get the Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` returns the socket wide defaults for the future associations.
+    pub fn get_rto_info(&self, assoc_id: AssociationId) -> std::io::Result<RtoInfo> {
+        sctp_get_rto_info_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` sets the socket wide defaults for the future associations. The
+    /// values are validated by the kernel, invalid values (eg. `min` larger than `max`) return an
+    /// error of the kind [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+    pub fn set_rto_info(&self, rto_info: RtoInfo) -> std::io::Result<()> {
+        sctp_set_rto_info_internal(&self.inner, rto_info)
+    }
+
+    /// Get the Association parameters. See Section 8.1.2 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` returns the socket wide defaults for the future associations.
+    pub fn get_assoc_info(&self, assoc_id: AssociationId) -> std::io::Result<AssocInfo> {
+        sctp_get_assoc_info_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the Association parameters. See Section 8.1.2 of RFC 6458.
+    ///
+    /// Only `max_rxt` and `cookie_life` can be set, the read-only values of the [`AssocInfo`]
+    /// (`peer_destinations`, `peer_rwnd` and `local_rwnd`) are ignored and are not changed.
+    pub fn set_assoc_info(&self, assoc_info: AssocInfo) -> std::io::Result<()> {
+        sctp_set_assoc_info_internal(&self.inner, assoc_info)
+    }
+
+    /// Set the parameters of the association setup for the future associations. See
+    /// [`HandshakeParams`][crate::HandshakeParams].
+    ///
+    /// If setting any of the underlying socket options fails, an error wrapping
+    /// [`SctpError::SocketOption`][crate::SctpError::SocketOption] that names the option is
+    /// returned.
+    pub fn set_handshake_params(&self, params: &HandshakeParams) -> std::io::Result<()> {
+        sctp_set_handshake_params_internal(&self.inner, params)
+    }
+
+    /// Get the maximum size of the `DATA` chunks (fragments) of the user messages. See Section
+    /// 8.1.16 of RFC 6458.
+    ///
+    /// A value of `0` means the fragment size is determined by the Path MTU.
+    pub fn maxseg(&self, assoc_id: AssociationId) -> std::io::Result<u32> {
+        sctp_get_maxseg_internal(&self.inner, assoc_id)
+    }
+
+    /// Set the maximum size of the `DATA` chunks (fragments) of the user messages. See Section
+    /// 8.1.16 of RFC 6458.
+    ///
+    /// A `maxseg` of `0` lets the fragment size be determined by the Path MTU. The kernel never
+    /// uses a size larger than allowed by the Path MTU, the effective value can be read back from
+    /// the `fragmentation_pt` of the [`ConnStatus`][crate::ConnStatus] of an association.
+    pub fn set_maxseg(&self, assoc_id: AssociationId, maxseg: u32) -> std::io::Result<()> {
+        sctp_set_maxseg_internal(&self.inner, assoc_id, maxseg)
+    }
+
+    /// Get whether the fragmentation of the user messages is disabled. See Section 8.1.11 of RFC
+    /// 6458.
+    pub fn disable_fragments(&self) -> std::io::Result<bool> {
+        sctp_get_disable_fragments_internal(&self.inner)
+    }
+
+    /// Disable (or enable) the fragmentation of the user messages. See Section 8.1.11 of RFC 6458.
+    ///
+    /// When the fragmentation is disabled, a message is either sent in a single `DATA` chunk or
+    /// the send fails with the `EMSGSIZE` OS error, if the message is larger than the
+    /// fragmentation point (the `fragmentation_pt` of the [`ConnStatus`][crate::ConnStatus]). The
+    /// fragmentation point is determined by the Path MTU or by the
+    /// [`set_maxseg`][Self::set_maxseg] value if smaller.
+    pub fn set_disable_fragments(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_disable_fragments_internal(&self.inner, on)
+    }
+
+    /// Get whether the Nagle like algorithm is disabled (`SCTP_NODELAY`). See Section 8.1.5 of
+    /// RFC 6458.
+    pub fn nodelay(&self) -> std::io::Result<bool> {
+        sctp_get_nodelay_internal(&self.inner)
+    }
+
+    /// Disable (or enable) the Nagle like algorithm (`SCTP_NODELAY`), ie. send the small messages
+    /// right away instead of bundling them. See Section 8.1.5 of RFC 6458.
+    pub fn set_nodelay(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_nodelay_internal(&self.inner, on)
+    }
+
+    /// Get the default PR-SCTP (RFC 3758) time to live of the messages sent on the association.
+    ///
+    /// Returns `None` if the messages are fully reliable by default (or use a PR-SCTP policy other
+    /// than the time to live).
+    pub fn default_pr_ttl(
+        &self,
+        assoc_id: AssociationId,
+    ) -> std::io::Result<Option<std::time::Duration>> {
+        sctp_get_default_pr_ttl_internal(&self.inner, assoc_id)
+    }
+
+    /// Configure the socket for a reliable, but bounded latency delivery (eg. for the media or
+    /// signaling).
+    ///
+    /// This is a preset that applies to all the current and future associations of the socket:
+    /// - The messages that are not sent within `max_latency` are abandoned, ie. the default
+    ///   PR-SCTP (RFC 3758) policy is the time to live of `max_latency`. This requires PR-SCTP
+    ///   support of the peer, otherwise the messages are fully reliable.
+    /// - The priority stream scheduler is used, so that the streams can be prioritized.
+    /// - The Nagle like algorithm is disabled ([`set_nodelay`][Self::set_nodelay]).
+    /// - The SACK delay is lowered to 10 milliseconds.
+    ///
+    /// The setting stops at the first failure, an error wrapping
+    /// [`SctpError::SocketOption`][crate::SctpError::SocketOption] tells the option that failed. A
+    /// `max_latency` of less than a millisecond returns an error of the kind
+    /// [`InvalidInput`][std::io::ErrorKind::InvalidInput].
+    pub fn configure_bounded_latency(
+        &self,
+        max_latency: std::time::Duration,
+    ) -> std::io::Result<()> {
+        sctp_configure_bounded_latency_internal(&self.inner, max_latency)
+    }
+
+    /// Set the Adaptation Layer Indication (See Section 8.1.10 of RFC 6458).
+    ///
+    /// The indication is carried in the `INIT` / `INIT-ACK` of the associations set up after this
+    /// call, so this should be called before connecting (or listening). The peer receives it as an
+    /// [`AdaptationIndication`][crate::Notification::AdaptationIndication] notification, if
+    /// subscribed to the [`AdaptationLayer`][crate::Event::AdaptationLayer] event. This is used by
+    /// the protocols that negotiate an adaptation code point during the handshake.
+    pub fn set_adaptation_indication(&self, ind: u32) -> std::io::Result<()> {
+        sctp_set_adaptation_indication_internal(&self.inner, ind)
+    }
+
+    /// Enable (or disable) User Message Interleaving (I-DATA chunks, RFC 8260) for the
+    /// associations of this socket.
+    ///
+    /// When enabling, the fragment interleave level is set to `2` first, as required for
+    /// interleaving. Interleaving is used on an association only if the peer enables it as well,
+    /// which can be verified using
+    /// [`ConnectedSocket::interleaving_active`][crate::ConnectedSocket::interleaving_active]. On
+    /// Linux, this fails with a `PermissionDenied` error unless the `net.sctp.intl_enable` sysctl
+    /// is set.
+    pub fn set_interleaving(&self, on: bool) -> std::io::Result<()> {
+        sctp_set_interleaving_internal(&self.inner, on)
+    }
+
+    /// Get the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// Note: On Linux, the kernel doubles the size set using
+    /// [`set_recv_buffer_size`][Self::set_recv_buffer_size] (to account for the bookkeeping
+    /// overhead), and the doubled size is returned here.
+    pub fn recv_buffer_size(&self) -> std::io::Result<usize> {
+        get_recv_buffer_size_internal(&self.inner)
+    }
+
+    /// Set the size of the receive buffer (`SO_RCVBUF`) of the socket.
+    ///
+    /// The size is capped by the kernel to `net.core.rmem_max`. See
+    /// [`recv_buffer_size`][Self::recv_buffer_size] for the Linux quirk when reading back the size.
+    pub fn set_recv_buffer_size(&self, size: usize) -> std::io::Result<()> {
+        set_recv_buffer_size_internal(&self.inner, size)
+    }
+
+    /// Get the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// Note: On Linux, the kernel doubles the size set using
+    /// [`set_send_buffer_size`][Self::set_send_buffer_size] (to account for the bookkeeping
+    /// overhead), and the doubled size is returned here.
+    pub fn send_buffer_size(&self) -> std::io::Result<usize> {
+        get_send_buffer_size_internal(&self.inner)
+    }
+
+    /// Set the size of the send buffer (`SO_SNDBUF`) of the socket.
+    ///
+    /// The size is capped by the kernel to `net.core.wmem_max`. See
+    /// [`send_buffer_size`][Self::send_buffer_size] for the Linux quirk when reading back the size.
+    pub fn set_send_buffer_size(&self, size: usize) -> std::io::Result<()> {
+        set_send_buffer_size_internal(&self.inner, size)
+    }
+
+    /// Get the time (in seconds) after which the idle associations are closed automatically.
+    /// See Section 8.1.8 of RFC 6458.
+    ///
+    /// Valid only for the One to Many sockets, see [`set_autoclose`][Self::set_autoclose].
+    pub fn autoclose(&self) -> std::io::Result<u32> {
+        sctp_get_autoclose_internal(&self.inner)
+    }
+
+    /// Set the time (in seconds) after which the idle associations are closed automatically.
+    /// See Section 8.1.8 of RFC 6458.
+    ///
+    /// A value of `0` disables the automatic close. Valid only for the One to Many sockets, on a
+    /// One to One socket an [`SctpError::OneToManyOnly`][crate::SctpError::OneToManyOnly] error is
+    /// returned.
+    pub fn set_autoclose(&self, secs: u32) -> std::io::Result<()> {
+        sctp_set_autoclose_internal(&self.inner, secs)
+    }
+
+    /// Set a shared key used for authenticating chunks. See Section 8.3.2 of RFC 6458.
+    ///
+    /// An `assoc_id` of `0` sets the key for the future associations on the socket.
+    pub fn set_shared_key(
+        &self,
+        assoc_id: AssociationId,
+        key_id: u16,
+        key: &[u8],
+    ) -> std::io::Result<()> {
+        sctp_set_shared_key_internal(&self.inner, assoc_id, key_id, key)?;
+        self.auth_keys.insert(assoc_id, key_id);
+        Ok(())
+    }
+
+    /// Make the shared key with `key_id` the active key for sending. See Section 8.3.3 of RFC
+    /// 6458.
+    ///
+    /// A different key may be used for a particular message using the
+    /// [`auth_key_id`][crate::SendData::auth_key_id] of the [`SendData`][crate::SendData].
+    pub fn activate_key(&self, assoc_id: AssociationId, key_id: u16) -> std::io::Result<()> {
+        sctp_activate_key_internal(&self.inner, assoc_id, key_id)
+    }
+
+    /// Get the active and the installed shared keys for the association. See Section 8.3 of RFC
+    /// 6458.
+    ///
+    /// The kernel does not report the installed keys, see [`AuthKeyState::installed`] for how
+    /// these are determined.
+    pub fn auth_key_state(&self, assoc_id: AssociationId) -> std::io::Result<AuthKeyState> {
+        sctp_auth_key_state_internal(&self.inner, &self.auth_keys, assoc_id)
+    }
+
+    /// Request that the chunks of the `chunk_type` are received authenticated. See Section 8.3.1
+    /// of RFC 6458.
+    ///
+    /// This applies only to the associations set up after the call.
+    pub fn set_auth_chunk(&self, chunk_type: u8) -> std::io::Result<()> {
+        sctp_set_auth_chunk_internal(&self.inner, chunk_type)
+    }
+
+    /// Set the remote UDP encapsulation port for SCTP over UDP. See RFC 6951.
+    ///
+    /// A `port` of `0` disables the encapsulation. An unspecified `addr` (eg. `0.0.0.0:0`) applies
+    /// the port to all the paths of the association, with an `assoc_id` of `0` this sets the
+    /// default for the future associations (this can be done before `connect`). Otherwise the
+    /// port is set only for the path to `addr`.
+    #[cfg(target_os = "linux")]
+    pub fn set_udp_encaps_port(
+        &self,
+        assoc_id: AssociationId,
+        addr: SocketAddr,
+        port: u16,
+    ) -> std::io::Result<()> {
+        sctp_set_udp_encaps_port_internal(&self.inner, assoc_id, addr, port)
+    }
+
+    /// Get the priority (`SO_PRIORITY`) of the socket.
+    #[cfg(target_os = "linux")]
+    pub fn priority(&self) -> std::io::Result<u32> {
+        get_priority_internal(&self.inner)
+    }
+
+    /// Set the priority (`SO_PRIORITY`) of the socket.
+    ///
+    /// The priority is used by the Linux queueing disciplines for scheduling the egress packets.
+    /// Setting a priority outside of `0` to `6` requires the `CAP_NET_ADMIN` capability.
+    #[cfg(target_os = "linux")]
+    pub fn set_priority(&self, prio: u32) -> std::io::Result<()> {
+        set_priority_internal(&self.inner, prio)
+    }
 }
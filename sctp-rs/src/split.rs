@@ -0,0 +1,111 @@
+//! Owned send and receive halves of a [`ConnectedSocket`].
+
+use std::sync::Arc;
+
+use crate::{ConnectedSocket, Incoming, NotificationOrData, ReceivedMessageInfo, SendData};
+
+/// The send half of a [`ConnectedSocket`], created by [`ConnectedSocket::split`].
+///
+/// Only the send side APIs are available on this half.
+#[derive(Debug)]
+pub struct SctpSender {
+    inner: Arc<ConnectedSocket>,
+}
+
+impl SctpSender {
+    /// Send Data and Anciliary data if any on the SCTP Socket.
+    ///
+    /// See [`ConnectedSocket::sctp_send`] for details.
+    pub async fn sctp_send(&self, data: SendData) -> std::io::Result<()> {
+        self.inner.sctp_send(data).await
+    }
+
+    /// Whether the most recent [`sctp_send`][Self::sctp_send] was blocked by the flow control.
+    ///
+    /// See [`ConnectedSocket::last_send_blocked`] for details.
+    pub fn last_send_blocked(&self) -> bool {
+        self.inner.last_send_blocked()
+    }
+
+    /// Send an ordered message and return the Stream Sequence Number assigned to it.
+    ///
+    /// See [`ConnectedSocket::send_tracked`] for details.
+    pub async fn send_tracked(&self, data: SendData) -> std::io::Result<u16> {
+        self.inner.send_tracked(data).await
+    }
+
+    /// Send each of the `payloads` as a separate message, distributing them over the `streams`
+    /// in turn.
+    ///
+    /// See [`ConnectedSocket::send_round_robin`] for details.
+    pub async fn send_round_robin(
+        &self,
+        payloads: &[Vec<u8>],
+        streams: &[u16],
+    ) -> std::io::Result<()> {
+        self.inner.send_round_robin(payloads, streams).await
+    }
+
+    /// Send the `payload` on the stream `sid`, split into the messages that fit in a single packet
+    /// each.
+    ///
+    /// See [`ConnectedSocket::send_fitting`] for details.
+    pub async fn send_fitting(&self, payload: &[u8], sid: u16) -> std::io::Result<usize> {
+        self.inner.send_fitting(payload, sid).await
+    }
+
+    /// Gracefully shutdown the association, no more data can be sent after this.
+    ///
+    /// The receive half keeps receiving the data until the peer completes the shutdown.
+    pub fn shutdown(&self) -> std::io::Result<()> {
+        self.inner.shutdown(std::net::Shutdown::Write)
+    }
+}
+
+/// The receive half of a [`ConnectedSocket`], created by [`ConnectedSocket::split`].
+///
+/// Only the receive side APIs are available on this half.
+#[derive(Debug)]
+pub struct SctpReceiver {
+    inner: Arc<ConnectedSocket>,
+}
+
+impl SctpReceiver {
+    /// Receive Data or Notification from the SCTP Socket.
+    ///
+    /// See [`ConnectedSocket::sctp_recv`] for details.
+    pub async fn sctp_recv(&self) -> std::io::Result<NotificationOrData> {
+        self.inner.sctp_recv().await
+    }
+
+    /// Receive Data or Notification into the given buffer.
+    ///
+    /// See [`ConnectedSocket::recv_into`] for details.
+    pub async fn recv_into(&self, buf: &mut Vec<u8>) -> std::io::Result<ReceivedMessageInfo> {
+        self.inner.recv_into(buf).await
+    }
+
+    /// Receive Data or Notification from the socket without waiting.
+    ///
+    /// See [`ConnectedSocket::try_recv`] for details.
+    pub fn try_recv(&self) -> std::io::Result<Option<NotificationOrData>> {
+        self.inner.try_recv()
+    }
+
+    /// Returns an [`Incoming`] adaptor to receive on this socket until the association is closed.
+    pub fn incoming(&self) -> Incoming<'_> {
+        self.inner.incoming()
+    }
+}
+
+// Split the socket into the halves sharing the socket. The socket is closed when both the halves
+// are dropped.
+pub(crate) fn split_internal(socket: ConnectedSocket) -> (SctpSender, SctpReceiver) {
+    let inner = Arc::new(socket);
+    (
+        SctpSender {
+            inner: inner.clone(),
+        },
+        SctpReceiver { inner },
+    )
+}
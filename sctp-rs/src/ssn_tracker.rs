@@ -0,0 +1,99 @@
+//! Tracking of the Stream Sequence Numbers of the received messages.
+
+use std::collections::HashMap;
+
+use crate::consts::SCTP_UNORDERED;
+use crate::{AssociationId, RcvInfo};
+
+/// A gap in the Stream Sequence Numbers (SSN) received on a stream of an association.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SsnGap {
+    /// Association ID of the association the gap is on.
+    pub assoc_id: AssociationId,
+
+    /// Stream ID of the stream the gap is on.
+    pub sid: u16,
+
+    /// First missing SSN, ie. the SSN that was expected next.
+    pub expected: u16,
+
+    /// SSN actually received, the SSNs from `expected` up to (but not including) `received` are
+    /// missing.
+    pub received: u16,
+}
+
+impl SsnGap {
+    /// Number of the missing messages.
+    pub fn missing(&self) -> u16 {
+        self.received.wrapping_sub(self.expected)
+    }
+}
+
+/// A helper to detect the gaps in the Stream Sequence Numbers (SSN) of the ordered messages
+/// received per association and stream.
+///
+/// With reliable SCTP, there should never be a gap in the SSNs of the ordered messages. With
+/// Partially Reliable SCTP (PR-SCTP, RFC 3758) however, the sender may abandon messages, which are
+/// then skipped by the receiver. The tracker consumes the [`RcvInfo`] of every received message
+/// (See [`sctp_request_rcvinfo`][crate::ConnectedSocket::sctp_request_rcvinfo]) and reports such
+/// gaps. Unordered messages do not carry a meaningful SSN and are ignored.
+///
+/// ```rust
+/// use sctp_rs::{RcvInfo, StreamSsnTracker};
+///
+/// let mut tracker = StreamSsnTracker::new();
+/// for ssn in [0, 1, 3] {
+///     let rcv_info = RcvInfo { sid: 1, ssn, assoc_id: 1.into(), ..Default::default() };
+///     if let Some(gap) = tracker.track(&rcv_info) {
+///         assert_eq!((gap.expected, gap.missing()), (2, 1));
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct StreamSsnTracker {
+    next_ssn: HashMap<(AssociationId, u16), u16>,
+}
+
+impl StreamSsnTracker {
+    /// Create a new tracker, that has not seen any messages yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track the SSN of a received message.
+    ///
+    /// Returns the [`SsnGap`] if the SSN is not the one expected next on the stream. The first
+    /// message on a stream is expected to have the SSN `0`.
+    pub fn track(&mut self, rcv_info: &RcvInfo) -> Option<SsnGap> {
+        if rcv_info.flags & SCTP_UNORDERED != 0 {
+            return None;
+        }
+
+        let next_ssn = self
+            .next_ssn
+            .entry((rcv_info.assoc_id, rcv_info.sid))
+            .or_insert(0);
+        let expected = *next_ssn;
+        *next_ssn = rcv_info.ssn.wrapping_add(1);
+
+        if rcv_info.ssn == expected {
+            None
+        } else {
+            Some(SsnGap {
+                assoc_id: rcv_info.assoc_id,
+                sid: rcv_info.sid,
+                expected,
+                received: rcv_info.ssn,
+            })
+        }
+    }
+
+    /// Forget all the streams of the given association.
+    ///
+    /// This should be called when an association is closed or restarted, since the SSNs start
+    /// again from `0` for a new association.
+    pub fn reset(&mut self, assoc_id: AssociationId) {
+        self.next_ssn.retain(|(id, _), _| *id != assoc_id);
+    }
+}
@@ -1,10 +1,78 @@
 //! Types used by the Public APIs
 
+use std::convert::TryInto;
+
+use crate::consts::{
+    SCTP_ABORT, SCTP_ADDR_OVER, SCTP_ALL_ASSOC, SCTP_CURRENT_ASSOC, SCTP_EOF, SCTP_FUTURE_ASSOC,
+    SCTP_PARTIAL_DELIVERY_ABORTED, SCTP_SACK_IMMEDIATELY, SCTP_SENDALL, SCTP_UNORDERED,
+};
+
 /// SCTP Association ID Type
-pub type AssociationId = i32;
+///
+/// The Association IDs are assigned by the kernel to the associations (See Section 3.1.3 of RFC
+/// 6458), these are always positive. The values `0` (`SCTP_FUTURE_ASSOC`), `1`
+/// (`SCTP_CURRENT_ASSOC`) and `2` (`SCTP_ALL_ASSOC`) are used as the sentinel values by some of
+/// the APIs (eg. `0` for the only association of a One to One socket or for the socket wide
+/// defaults). The kernel assigns Association IDs starting from `3`.
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct AssociationId(i32);
+
+impl AssociationId {
+    /// Create a new [`AssociationId`]. Returns `None` for the negative values, which are never
+    /// valid Association IDs.
+    pub fn new(id: i32) -> Option<Self> {
+        if id < 0 {
+            None
+        } else {
+            Some(Self(id))
+        }
+    }
+
+    /// Returns the Association ID as an `i32`.
+    pub fn as_i32(self) -> i32 {
+        self.0
+    }
+
+    /// Returns `true` if this may be an Association ID assigned by the kernel to an association,
+    /// ie. it's neither negative nor one of the sentinel values.
+    pub fn is_valid(self) -> bool {
+        self.0 > SCTP_ALL_ASSOC
+    }
+
+    /// Returns `true` if this is one of the sentinel values (`SCTP_FUTURE_ASSOC`,
+    /// `SCTP_CURRENT_ASSOC` or `SCTP_ALL_ASSOC`).
+    pub fn is_sentinel(self) -> bool {
+        (0..=SCTP_ALL_ASSOC).contains(&self.0)
+    }
+}
+
+impl From<i32> for AssociationId {
+    fn from(id: i32) -> Self {
+        Self(id)
+    }
+}
+
+impl From<AssociationId> for i32 {
+    fn from(id: AssociationId) -> Self {
+        id.0
+    }
+}
+
+impl std::fmt::Display for AssociationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
 /// Flags used by `sctp_bindx`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BindxFlags {
     /// Add the addresses passed (corresponding to `SCTP_BINDX_ADD_ADDR`)
     Add,
@@ -15,6 +83,7 @@ pub enum BindxFlags {
 
 /// SocketToAssociation: One-to-Many or One-to-One style Socket
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SocketToAssociation {
     /// One Association per Socket (TCP Style Socket.)
     OneToOne,
@@ -25,6 +94,7 @@ pub enum SocketToAssociation {
 
 /// NotificationOrData: A type returned by a `sctp_recv` call.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NotificationOrData {
     /// SCTP Notification received by an `sctp_recv` call.
     Notification(Notification),
@@ -38,6 +108,7 @@ pub enum NotificationOrData {
 /// This structure is returned by the `sctp_recv` API call. This contains in addition to 'received'
 /// data, any ancillary data that is received during the underlying system call.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReceivedData {
     /// Received Message Payload.
     pub payload: Vec<u8>,
@@ -49,26 +120,54 @@ pub struct ReceivedData {
     pub nxt_info: Option<NxtInfo>,
 }
 
+/// Structure Representing the information about a message received in a caller provided buffer.
+///
+/// This structure is returned by the `recv_into` API call. The received payload (or the raw
+/// Notification) is in the buffer passed to the call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReceivedMessageInfo {
+    /// Whether a Notification is received. The Notification can be parsed from the buffer using
+    /// [`Notification::from_bytes`].
+    pub notification: bool,
+
+    /// Optional ancillary information about the received payload.
+    pub rcv_info: Option<RcvInfo>,
+
+    /// Optional ancillary information about the next call to `sctp_recv`.
+    pub nxt_info: Option<NxtInfo>,
+
+    /// Whether the complete message is received. A message larger than the buffer is received
+    /// in multiple parts, only the last part has `eor` set.
+    pub eor: bool,
+}
+
 /// Structure Represnting Data to be Sent.
 ///
 /// This structure contains actual paylod and optional ancillary data.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SendData {
     /// Received Message Payload.
     pub payload: Vec<u8>,
 
     /// Optional ancillary information used to send the data.
     pub snd_info: Option<SendInfo>,
+
+    /// Optional Key Identifier of the shared key used to authenticate the data (See Section
+    /// 5.3.8 of RFC 6458). When `None`, the active key of the association is used.
+    pub auth_key_id: Option<u16>,
 }
 
 /// Structure representing Ancilliary Send Information (See Section 5.3.4 of RFC 6458)
 #[repr(C)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SendInfo {
     /// Stream ID of the stream to send the data on.
     pub sid: u16,
 
-    /// Flags to be used while sending the data.
+    /// Flags to be used while sending the data. See [`SendFlags`].
     pub flags: u16,
 
     /// Application Protocol ID to be used while sending the data.
@@ -81,9 +180,91 @@ pub struct SendInfo {
     pub assoc_id: AssociationId,
 }
 
+impl SendInfo {
+    /// Create the [`SendInfo`] to send the data on the stream `sid`, with all the other fields
+    /// set to their defaults.
+    ///
+    /// This is useful when the stream IDs are computed (eg. as an index), a stream ID that does
+    /// not fit in the 16 bit SCTP stream IDs returns [`SctpError::InvalidStreamId`], rather than
+    /// silently wrapping around.
+    pub fn for_stream(sid: usize) -> Result<Self, SctpError> {
+        Ok(Self {
+            sid: stream_id(sid)?,
+            ..Default::default()
+        })
+    }
+}
+
+/// Flags used while sending a message (See Section 5.3.4 of RFC 6458)
+///
+/// The flags can be combined using `|`, eg. `SendFlags::UNORDERED | SendFlags::SACK_IMMEDIATELY`.
+/// The raw value (to be used as [`SendInfo::flags`]) is obtained using [`SendFlags::bits`].
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct SendFlags(u16);
+
+impl SendFlags {
+    /// No flags set.
+    pub const NONE: Self = Self(0);
+
+    /// Send the message unordered (`SCTP_UNORDERED`).
+    pub const UNORDERED: Self = Self(SCTP_UNORDERED);
+
+    /// Override the primary destination address with the given address (`SCTP_ADDR_OVER`).
+    pub const ADDR_OVER: Self = Self(SCTP_ADDR_OVER);
+
+    /// Abort the association, the message is the user initiated abort reason (`SCTP_ABORT`).
+    pub const ABORT: Self = Self(SCTP_ABORT);
+
+    /// Request the peer to acknowledge the message immediately (`SCTP_SACK_IMMEDIATELY`).
+    pub const SACK_IMMEDIATELY: Self = Self(SCTP_SACK_IMMEDIATELY);
+
+    /// Send the message on all the associations of a One to Many socket (`SCTP_SENDALL`).
+    pub const SENDALL: Self = Self(SCTP_SENDALL);
+
+    /// Gracefully shutdown the association after sending the message (`SCTP_EOF`).
+    pub const EOF: Self = Self(SCTP_EOF);
+
+    /// Returns the raw value of the flags.
+    pub fn bits(self) -> u16 {
+        self.0
+    }
+
+    /// Returns `true` if all the flags in `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for SendFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for SendFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl From<SendFlags> for u16 {
+    fn from(flags: SendFlags) -> Self {
+        flags.0
+    }
+}
+
 /// Structure Representing Ancillary Receive Information (See Section 5.3.5 of RFC 6458)
 #[repr(C)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RcvInfo {
     /// Stream ID on which the data is received.
     pub sid: u16,
@@ -113,6 +294,7 @@ pub struct RcvInfo {
 /// Structure representing Ancillary next information (See Section 5.3.5)
 #[repr(C)]
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NxtInfo {
     /// Stream ID for the next received data.
     pub sid: u16,
@@ -131,16 +313,17 @@ pub struct NxtInfo {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// An `enum` representing the notifications received on the SCTP Sockets.
 pub enum Notification {
     /// Association Change Notification. See Section 6.1.1 of RFC 6458.
     AssociationChange(AssociationChange),
 
     /// Peer Address Change Notification. See Section 6.1.2 of RFC 6458.
-    PeerAddrChange,
+    PeerAddrChange(PeerAddrChange),
 
-    /// Send Failed Notification. See Section 6.1.4 of RFC 6458. Deprecated.
-    SendFailed,
+    /// Send Failed Notification. See Section 6.1.4 and Section 6.1.11 of RFC 6458.
+    SendFailed(SendFailed),
 
     /// Remote Operation Error Notification. See Section 6.1.3 of RFC 6458.
     RemoteError,
@@ -149,10 +332,10 @@ pub enum Notification {
     Shutdown(Shutdown),
 
     /// Partial Delivery Event Notification. See Section 6.1.7 of RFC 6458.
-    PartialDeliveryEvent,
+    PartialDeliveryEvent(PdapiEvent),
 
     /// Adaptation Indication Notification. See Section 6.1.6 of RFC 6458.
-    AdaptationIndication,
+    AdaptationIndication(AdaptationEvent),
 
     /// Authentication Event Notification. See Section 6.1.8 of RFC 6458.
     AuthenticationEvent,
@@ -164,12 +347,120 @@ pub enum Notification {
     Unsupported,
 }
 
+impl Notification {
+    /// Parses a raw notification buffer as received from the kernel. See Section 6.1 of RFC 6458.
+    ///
+    /// Buffers shorter than the notification structure of their type are never read past their
+    /// end, such buffers are returned as [`Unsupported`][`Notification::Unsupported`]. Variable
+    /// length data (eg. [`AssociationChange::info`]) is bound to the `length` reported in the
+    /// notification header.
+    pub fn from_bytes(data: &[u8]) -> Self {
+        crate::internal::notification_from_message(data)
+    }
+
+    /// Returns the Association ID of the association the notification is about.
+    ///
+    /// This is useful for routing notifications received on a One to Many socket to the
+    /// association they belong to. Returns `None` for the notifications that do not carry an
+    /// Association ID.
+    pub fn assoc_id(&self) -> Option<AssociationId> {
+        match self {
+            Self::AssociationChange(AssociationChange { assoc_id, .. }) => Some(*assoc_id),
+            Self::PeerAddrChange(PeerAddrChange { assoc_id, .. }) => Some(*assoc_id),
+            Self::Shutdown(Shutdown { assoc_id, .. }) => Some(*assoc_id),
+            Self::AdaptationIndication(AdaptationEvent { assoc_id, .. }) => Some(*assoc_id),
+            Self::SendFailed(SendFailed { assoc_id, .. }) => Some(*assoc_id),
+            Self::PartialDeliveryEvent(PdapiEvent { assoc_id, .. }) => Some(*assoc_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Event`] kind of the notification.
+    ///
+    /// This allows the dispatch code to match on a single `enum` for all the notifications.
+    /// [`Unsupported`][`Notification::Unsupported`] notifications return [`Event::Unknown`].
+    pub fn event_type(&self) -> Event {
+        match self {
+            Self::AssociationChange(_) => Event::Association,
+            Self::PeerAddrChange(_) => Event::Address,
+            Self::SendFailed(send_failed) => send_failed.ev_type.clone(),
+            Self::RemoteError => Event::PeerError,
+            Self::Shutdown(_) => Event::Shutdown,
+            Self::PartialDeliveryEvent(_) => Event::PartialDelivery,
+            Self::AdaptationIndication(_) => Event::AdaptationLayer,
+            Self::AuthenticationEvent => Event::Authentication,
+            Self::SenderDryEvent => Event::SenderDry,
+            Self::Unsupported => Event::Unknown,
+        }
+    }
+}
+
+/// What is done when an [`Unsupported`][Notification::Unsupported] Notification is received.
+///
+/// The policy of a socket is set using
+/// [`set_unsupported_notification_policy`][crate::ConnectedSocket::set_unsupported_notification_policy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnsupportedNotificationPolicy {
+    /// Return the [`Unsupported`][Notification::Unsupported] Notification, this is the default.
+    Return,
+
+    /// Skip the Notification and receive the next Notification or Data.
+    Skip,
+
+    /// Return an error wrapping [`SctpError::UnsupportedNotification`].
+    Error,
+}
+
+impl UnsupportedNotificationPolicy {
+    // Apply the policy to a received Notification or Data. Returns `None` if the received
+    // Notification is to be skipped. Everything other than an `Unsupported` Notification is
+    // returned as is.
+    pub(crate) fn apply(
+        self,
+        received: NotificationOrData,
+    ) -> std::io::Result<Option<NotificationOrData>> {
+        if received != NotificationOrData::Notification(Notification::Unsupported) {
+            return Ok(Some(received));
+        }
+
+        match self {
+            Self::Return => Ok(Some(received)),
+            Self::Skip => {
+                log::debug!("Skipping Unsupported Notification.");
+                Ok(None)
+            }
+            Self::Error => Err(SctpError::UnsupportedNotification.into()),
+        }
+    }
+}
+
+/// Concise, single line format of the notification suitable for logging, eg.
+/// `AssocChange[assoc=3 state=CommUp in=10 out=10]`. Use the `Debug` format for all the details.
+impl std::fmt::Display for Notification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AssociationChange(assoc_change) => write!(f, "{}", assoc_change),
+            Self::PeerAddrChange(peer_addr_change) => write!(f, "{}", peer_addr_change),
+            Self::Shutdown(shutdown) => write!(f, "{}", shutdown),
+            Self::SendFailed(send_failed) => write!(f, "{}", send_failed),
+            Self::RemoteError => write!(f, "RemoteError"),
+            Self::PartialDeliveryEvent(pdapi) => write!(f, "{}", pdapi),
+            Self::AdaptationIndication(adaptation) => write!(f, "{}", adaptation),
+            Self::AuthenticationEvent => write!(f, "Authentication"),
+            Self::SenderDryEvent => write!(f, "SenderDry"),
+            Self::Unsupported => write!(f, "Unsupported"),
+        }
+    }
+}
+
 /// AssociationChange: Structure returned as notification for Association Change.
 ///
 /// To subscribe to this notification type, An application should call `sctp_subscribe_event` using
 /// the [`Event`] type as [`Event::Association`].
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AssociationChange {
     /// Type of the Notification always `SCTP_ASSOC_CHAGE`
     pub ev_type: Event,
@@ -199,12 +490,142 @@ pub struct AssociationChange {
     pub info: Vec<u8>,
 }
 
+impl AssociationChange {
+    /// Returns the features supported by the association.
+    ///
+    /// For the [`CommUp`][`AssocChangeState::CommUp`] and [`Restart`][`AssocChangeState::Restart`]
+    /// notifications, the trailing `info` bytes list the features supported by the association
+    /// (See Section 6.1.1 of RFC 6458). This allows an application to learn, for example,
+    /// whether the peer supports partial reliability before trying to use it. When the kernel
+    /// does not report the features (`info` is empty, as is the case with Linux kernels) or the
+    /// notification is for any other state, all the features are reported as unknown (`None`).
+    pub fn features(&self) -> AssocFeatures {
+        use crate::consts::*;
+
+        let known = matches!(
+            self.state,
+            AssocChangeState::CommUp | AssocChangeState::Restart
+        ) && !self.info.is_empty();
+        if !known {
+            return AssocFeatures::default();
+        }
+
+        let supports = |feature| Some(self.info.contains(&feature));
+        AssocFeatures {
+            pr: supports(SCTP_ASSOC_SUPPORTS_PR),
+            auth: supports(SCTP_ASSOC_SUPPORTS_AUTH),
+            asconf: supports(SCTP_ASSOC_SUPPORTS_ASCONF),
+            multibuf: supports(SCTP_ASSOC_SUPPORTS_MULTIBUF),
+            reconfig: supports(SCTP_ASSOC_SUPPORTS_RE_CONFIG),
+        }
+    }
+}
+
+/// AuthKeyState: State of the shared keys used for authenticating chunks of an association.
+///
+/// Returned by the `auth_key_state` API call. See Section 8.3 of RFC 6458.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthKeyState {
+    /// Key Identifier of the active key used for sending.
+    pub active: u16,
+
+    /// Key Identifiers of all the installed keys, in the ascending order.
+    ///
+    /// The kernel does not provide a way to list the installed keys, so these are the keys
+    /// installed using this crate (along with the null key `0` installed by the kernel). The keys
+    /// of the socket (`assoc_id` `0`) are inherited by the sockets created from it (eg.
+    /// [`ConnectedSocket`][crate::ConnectedSocket] returned by `accept` or `connect`).
+    pub installed: Vec<u16>,
+}
+
+impl std::fmt::Display for AssociationChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AssocChange[assoc={} state={:?} in={} out={}",
+            self.assoc_id, self.state, self.ib_streams, self.ob_streams
+        )?;
+        if self.error != 0 {
+            write!(f, " error={}", self.error)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// AssocFeatures: Features supported by an association.
+///
+/// Returned by [`AssociationChange::features`]. A value of `None` means that it is not known
+/// whether the feature is supported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssocFeatures {
+    /// Partial Reliability Extension (RFC 3758).
+    pub pr: Option<bool>,
+
+    /// Authenticated Chunks (RFC 4895).
+    pub auth: Option<bool>,
+
+    /// Dynamic Address Reconfiguration (RFC 5061).
+    pub asconf: Option<bool>,
+
+    /// Multiple buffering (`SCTP_ASSOC_SUPPORTS_MULTIBUF`).
+    pub multibuf: Option<bool>,
+
+    /// Stream Reconfiguration (RFC 6525).
+    pub reconfig: Option<bool>,
+}
+
+/// PeerAddrChange: Structure returned as notification for Peer Address Change.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_event` using
+/// the [`Event`] type as [`Event::Address`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerAddrChange {
+    /// Type of the Notification always `SCTP_PEER_ADDR_CHANGE`
+    pub ev_type: Event,
+
+    /// Notification Flags. Unused currently.
+    pub flags: u16,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Peer Address that the notification is about.
+    pub address: std::net::SocketAddr,
+
+    /// Peer Address state. See also [`PeerAddrState`].
+    pub state: PeerAddrState,
+
+    /// Error when state is an error state and error information is available.
+    pub error: i32,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+}
+
+impl std::fmt::Display for PeerAddrChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PeerAddrChange[assoc={} addr={} state={:?}",
+            self.assoc_id, self.address, self.state
+        )?;
+        if self.error != 0 {
+            write!(f, " error={}", self.error)?;
+        }
+        write!(f, "]")
+    }
+}
+
 /// Shutdown: Structure rreturned as notification for Shutdown Event.
 ///
 ///To subscribe to this notification type, An application should call `sctp_subscribe_event` using
 ///the [`Event`] ty[e as [`Event::Shutdown`]
 #[repr(C)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shutdown {
     /// Type of the Notification always `SCTP_SHUTDOWN`
     pub ev_type: Event,
@@ -219,11 +640,165 @@ pub struct Shutdown {
     pub assoc_id: AssociationId,
 }
 
+impl std::fmt::Display for Shutdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Shutdown[assoc={}]", self.assoc_id)
+    }
+}
+
+/// SendFailed: Structure returned as notification for a message that could not be delivered.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_events`
+/// using the [`Event`] type as [`Event::SendFailureEvent`] (See Section 6.1.11 of RFC 6458) or the
+/// deprecated [`Event::SendFailure`] (See Section 6.1.4 of RFC 6458). Both are reported using
+/// this structure, `ev_type` tells which one was received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SendFailed {
+    /// Type of the Notification `SCTP_SEND_FAILED_EVENT` or `SCTP_SEND_FAILED`
+    pub ev_type: Event,
+
+    /// Notification Flags, whether the message was sent on the wire (`SCTP_DATA_SENT`) or not
+    /// (`SCTP_DATA_UNSENT`).
+    pub flags: u16,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Error (Cause Code) indicating the reason of the failure.
+    pub error: u32,
+
+    /// Send information of the failed message, as it was used for sending the message.
+    pub info: SendInfo,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+
+    /// Payload of the failed message (or a part of it).
+    pub data: Vec<u8>,
+}
+
+impl SendFailed {
+    /// Returns the opaque `context` of the failed message, as set in the [`SendInfo`] used for
+    /// sending it.
+    ///
+    /// This allows correlating the failed message with the application's own bookkeeping (eg. the
+    /// pending requests).
+    pub fn context(&self) -> u32 {
+        self.info.context
+    }
+}
+
+impl std::fmt::Display for SendFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SendFailed[assoc={} sid={} context={} error={}]",
+            self.assoc_id, self.info.sid, self.info.context, self.error
+        )
+    }
+}
+
+/// AdaptationEvent: Structure returned as notification for the Adaptation Layer Indication sent by
+/// the peer in the `INIT` or `INIT-ACK`. See Section 6.1.6 of RFC 6458.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_events`
+/// using the [`Event`] type as [`Event::AdaptationLayer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdaptationEvent {
+    /// Type of the Notification always `SCTP_ADAPTATION_INDICATION`
+    pub ev_type: Event,
+
+    /// Notification Flags. Unused currently.
+    pub flags: u16,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Adaptation Layer Indication sent by the peer.
+    pub adaptation_ind: u32,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+}
+
+impl std::fmt::Display for AdaptationEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Adaptation[assoc={} ind={:#x}]",
+            self.assoc_id, self.adaptation_ind
+        )
+    }
+}
+
+/// PdapiEvent: Structure returned as notification for the Partial Delivery Event.
+///
+/// This notification is received when the partial delivery of a message, that is received by the
+/// application in multiple parts, is aborted. The parts of the message already received should be
+/// discarded.
+///
+/// To subscribe to this notification type, An application should call `sctp_subscribe_events`
+/// using the [`Event`] type as [`Event::PartialDelivery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PdapiEvent {
+    /// Type of the Notification always `SCTP_PARTIAL_DELIVERY_EVENT`
+    pub ev_type: Event,
+
+    /// Notification Flags. Unused currently.
+    pub flags: u16,
+
+    /// Length of the notification data.
+    pub length: u32,
+
+    /// Indication of the event, `SCTP_PARTIAL_DELIVERY_ABORTED` (`0`) is the only indication
+    /// currently defined.
+    pub indication: u32,
+
+    /// Association ID for the event.
+    pub assoc_id: AssociationId,
+
+    /// Stream ID of the message whose partial delivery is aborted.
+    pub stream: u32,
+
+    /// Stream Sequence Number of the message whose partial delivery is aborted.
+    pub seq: u32,
+}
+
+impl PdapiEvent {
+    /// Whether the partial delivery is aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.indication == SCTP_PARTIAL_DELIVERY_ABORTED
+    }
+
+    /// The [`stream`][Self::stream] as a 16 bit SCTP stream ID.
+    pub fn sid(&self) -> Result<u16, SctpError> {
+        stream_id(self.stream as usize)
+    }
+}
+
+impl std::fmt::Display for PdapiEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PartialDelivery[assoc={} stream={} ssn={}",
+            self.assoc_id, self.stream, self.seq
+        )?;
+        if self.is_aborted() {
+            write!(f, " aborted")?;
+        }
+        write!(f, "]")
+    }
+}
+
 /// Event: Used for Subscribing for SCTP Events
 ///
 /// See [`sctp_subscribe_events`][`crate::Listener::sctp_subscribe_event`] for the usage.
 #[repr(u16)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Event {
     /// Event to receive ancillary information with every `sctp_recv`.
     DataIo = (1 << 15),
@@ -272,26 +847,73 @@ pub enum Event {
     Unknown,
 }
 
+// All the events that can be delivered as a `Notification` (ie. everything except `DataIo`).
+pub(crate) const NOTIFICATION_EVENTS: [Event; 13] = [
+    Event::Association,
+    Event::Address,
+    Event::SendFailure,
+    Event::PeerError,
+    Event::Shutdown,
+    Event::PartialDelivery,
+    Event::AdaptationLayer,
+    Event::Authentication,
+    Event::SenderDry,
+    Event::StreamReset,
+    Event::AssociationReset,
+    Event::StreamChange,
+    Event::SendFailureEvent,
+];
+
 impl Event {
-    pub(crate) fn from_u16(val: u16) -> Self {
+    /// Returns the [`Event`] for the event type value used by the kernel (eg. the `sn_type` of a
+    /// Notification). Values that are not known return [`Event::Unknown`].
+    pub fn from_u16(val: u16) -> Self {
+        use crate::consts::*;
+
         match val {
-            0x8000 => Event::DataIo,
-            0x8001 => Event::Association,
-            0x8002 => Event::Address,
-            0x8003 => Event::SendFailure,
-            0x8004 => Event::PeerError,
-            0x8005 => Event::Shutdown,
-            0x8006 => Event::PartialDelivery,
-            0x8007 => Event::AdaptationLayer,
-            0x8008 => Event::Authentication,
-            0x8009 => Event::SenderDry,
-            0x800A => Event::StreamReset,
-            0x800B => Event::AssociationReset,
-            0x800C => Event::StreamChange,
-            0x800D => Event::SendFailureEvent,
+            SCTP_DATA_IO_EVENT => Event::DataIo,
+            SCTP_ASSOC_CHANGE => Event::Association,
+            SCTP_PEER_ADDR_CHANGE => Event::Address,
+            SCTP_SEND_FAILED => Event::SendFailure,
+            SCTP_REMOTE_ERROR => Event::PeerError,
+            SCTP_SHUTDOWN => Event::Shutdown,
+            SCTP_PARTIAL_DELIVERY_EVENT => Event::PartialDelivery,
+            SCTP_ADAPTATION_INDICATION => Event::AdaptationLayer,
+            SCTP_AUTHENTICATION_EVENT => Event::Authentication,
+            SCTP_SENDER_DRY_EVENT => Event::SenderDry,
+            SCTP_STREAM_RESET_EVENT => Event::StreamReset,
+            SCTP_ASSOC_RESET_EVENT => Event::AssociationReset,
+            SCTP_STREAM_CHANGE_EVENT => Event::StreamChange,
+            SCTP_SEND_FAILED_EVENT => Event::SendFailureEvent,
             _ => Event::Unknown,
         }
     }
+
+    /// Returns the event type value used by the kernel for the [`Event`], the inverse of
+    /// [`from_u16`][Self::from_u16].
+    ///
+    /// [`Event::Unknown`] does not have a value used by the kernel, `0` is returned for it.
+    pub fn as_u16(&self) -> u16 {
+        use crate::consts::*;
+
+        match self {
+            Event::DataIo => SCTP_DATA_IO_EVENT,
+            Event::Association => SCTP_ASSOC_CHANGE,
+            Event::Address => SCTP_PEER_ADDR_CHANGE,
+            Event::SendFailure => SCTP_SEND_FAILED,
+            Event::PeerError => SCTP_REMOTE_ERROR,
+            Event::Shutdown => SCTP_SHUTDOWN,
+            Event::PartialDelivery => SCTP_PARTIAL_DELIVERY_EVENT,
+            Event::AdaptationLayer => SCTP_ADAPTATION_INDICATION,
+            Event::Authentication => SCTP_AUTHENTICATION_EVENT,
+            Event::SenderDry => SCTP_SENDER_DRY_EVENT,
+            Event::StreamReset => SCTP_STREAM_RESET_EVENT,
+            Event::AssociationReset => SCTP_ASSOC_RESET_EVENT,
+            Event::StreamChange => SCTP_STREAM_CHANGE_EVENT,
+            Event::SendFailureEvent => SCTP_SEND_FAILED_EVENT,
+            Event::Unknown => 0,
+        }
+    }
 }
 
 /// SubscribeEventAssocId: AssociationID Used for Event Subscription
@@ -299,6 +921,7 @@ impl Event {
 /// Note: repr should be same as `AssociationId` (ie. `i32`)
 #[repr(i32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubscribeEventAssocId {
     /// Subscribe to Future Association IDs
     Future,
@@ -316,9 +939,9 @@ pub enum SubscribeEventAssocId {
 impl From<SubscribeEventAssocId> for AssociationId {
     fn from(value: SubscribeEventAssocId) -> Self {
         match value {
-            SubscribeEventAssocId::Future => 0 as Self,
-            SubscribeEventAssocId::Current => 1 as Self,
-            SubscribeEventAssocId::All => 2 as Self,
+            SubscribeEventAssocId::Future => Self(SCTP_FUTURE_ASSOC),
+            SubscribeEventAssocId::Current => Self(SCTP_CURRENT_ASSOC),
+            SubscribeEventAssocId::All => Self(SCTP_ALL_ASSOC),
             SubscribeEventAssocId::Value(v) => v,
         }
     }
@@ -327,6 +950,7 @@ impl From<SubscribeEventAssocId> for AssociationId {
 /// Association Change States
 #[repr(u16)]
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AssocChangeState {
     /// SCTP communication up.
     CommUp = 0,
@@ -360,9 +984,55 @@ impl AssocChangeState {
     }
 }
 
+/// Peer Address States reported in the [`PeerAddrChange`] notification.
+#[repr(i32)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PeerAddrState {
+    /// Address is now reachable.
+    Available = 0,
+
+    /// Address is no longer reachable.
+    Unreachable,
+
+    /// Address is no longer part of the association.
+    Removed,
+
+    /// Address is now part of the association.
+    Added,
+
+    /// Address has become the primary destination address.
+    MadePrim,
+
+    /// Address is now confirmed as a valid destination address.
+    Confirmed,
+
+    /// Address is potentially failed.
+    PotentiallyFailed,
+
+    /// Unknown State: This value indicates an error
+    Unknown,
+}
+
+impl PeerAddrState {
+    pub(crate) fn from_i32(val: i32) -> Self {
+        match val {
+            0 => PeerAddrState::Available,
+            1 => PeerAddrState::Unreachable,
+            2 => PeerAddrState::Removed,
+            3 => PeerAddrState::Added,
+            4 => PeerAddrState::MadePrim,
+            5 => PeerAddrState::Confirmed,
+            6 => PeerAddrState::PotentiallyFailed,
+            _ => PeerAddrState::Unknown,
+        }
+    }
+}
+
 /// Constants related to `enum sctp_cmsg_type`
 #[repr(i32)]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CmsgType {
     Init = 0,
     SndRcv,
@@ -378,16 +1048,33 @@ pub enum CmsgType {
 /// Constants related to `enum sctp_sstat_state`
 #[repr(i32)]
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnState {
     #[default]
     Empty = 0,
+
+    /// The association is closed.
     Closed,
+
+    /// `INIT` is sent, waiting for the `INIT ACK`.
     CookieWait,
+
+    /// `COOKIE ECHO` is sent, waiting for the `COOKIE ACK`.
     CookieEchoed,
+
+    /// The association is established.
     Established,
+
+    /// Shutdown is initiated, waiting for the outstanding data to be acknowledged.
     ShutdownPending,
+
+    /// `SHUTDOWN` is sent, waiting for the `SHUTDOWN ACK`.
     ShutdownSent,
+
+    /// `SHUTDOWN` is received from the peer.
     ShutdownReceived,
+
+    /// `SHUTDOWN ACK` is sent, waiting for the `SHUTDOWN COMPLETE`.
     ShutdownAckSent,
 
     Unknown, // Should never be seen.
@@ -412,6 +1099,7 @@ impl ConnState {
 
 /// PeerAddress: Structure representing SCTP Peer Address.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PeerAddress {
     pub assoc_id: AssociationId,
     pub address: std::net::SocketAddr,
@@ -424,6 +1112,7 @@ pub struct PeerAddress {
 
 /// ConnStatus: Status of an SCTP Connection
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConnStatus {
     pub assoc_id: AssociationId,
     pub state: ConnState,
@@ -436,4 +1125,237 @@ pub struct ConnStatus {
     pub peer_primary: PeerAddress,
 }
 
+/// RtoInfo: Retransmission Timeout (RTO) parameters. See Section 8.1.1 of RFC 6458.
+///
+/// All the values are in miliseconds. While setting the values, a value of zero means 'no
+/// change'. The values apply to all the paths of the association, see [`PeerAddrParams`] for the
+/// per path parameters.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RtoInfo {
+    /// Association ID, `0` for the socket wide defaults used by the future associations.
+    pub assoc_id: AssociationId,
+
+    /// Initial RTO value.
+    pub initial: u32,
+
+    /// Maximum RTO value.
+    pub max: u32,
+
+    /// Minimum RTO value.
+    pub min: u32,
+}
+
+/// AssocInfo: Association parameters. See Section 8.1.2 of RFC 6458.
+///
+/// While setting the values, a value of zero means 'no change'. `peer_destinations`, `peer_rwnd`
+/// and `local_rwnd` are read-only, they are ignored while setting the values.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AssocInfo {
+    /// Association ID, `0` for the socket wide defaults used by the future associations.
+    pub assoc_id: AssociationId,
+
+    /// Maximum retransmissions for the association.
+    pub max_rxt: u16,
+
+    /// Number of destination addresses of the peer (read-only).
+    pub peer_destinations: u16,
+
+    /// Current receive window of the peer (read-only).
+    pub peer_rwnd: u32,
+
+    /// Last receive window reported to the peer (read-only).
+    pub local_rwnd: u32,
+
+    /// Cookie life time (in miliseconds) used by the association.
+    pub cookie_life: u32,
+}
+
+/// PeerAddrParams: Parameters of a Peer Address of an association. See Section 8.1.12 of RFC 6458.
+///
+/// When `address` is `None`, the parameters apply to all the paths of the association (or to the
+/// socket defaults, when the association is not specified). `flags` is a combination of the
+/// `SPP_*` flags, which determine which of the values below are actually applied, a value of zero
+/// for a field means 'no change' while setting the parameters.
+///
+/// Note: There are no per path Retransmission Timeout (RTO) parameters, the `sctp_paddrparams`
+/// structure of the Linux kernel (as of Linux 6.x, and also of FreeBSD) carries no RTO fields.
+/// The RTO parameters set using [`RtoInfo`] apply to all the paths of an association. Only
+/// `path_max_rxt` can be tuned per path, which determines how fast a path is considered
+/// unreachable.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PeerAddrParams {
+    pub assoc_id: AssociationId,
+    pub address: Option<std::net::SocketAddr>,
+    pub hb_interval: u32, // in miliseconds
+    pub path_max_rxt: u16,
+    pub path_mtu: u32,
+    pub sack_delay: u32, // in miliseconds
+    pub flags: u32,
+    pub ipv6_flowlabel: u32,
+    pub dscp: u8,
+}
+
+/// SctpError: Errors specific to the SCTP APIs.
+///
+/// The APIs return [`std::io::Error`]s, an [`SctpError`] is wrapped inside the
+/// [`std::io::Error`] and can be obtained using [`SctpError::from_io_error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SctpError {
+    /// Association initialization parameters (`SCTP_INITMSG`) were set on a connected One to One
+    /// socket, where they have no effect.
+    InitMsgAfterConnect,
+
+    /// An option valid only on the One to Many (UDP Style) sockets (eg. `SCTP_AUTOCLOSE`) was set
+    /// on a One to One socket.
+    OneToManyOnly,
+
+    /// Creating the SCTP socket failed. `family` is the address family (`AF_INET` or `AF_INET6`)
+    /// and `style` is the style of the socket attempted, `errno` is the OS error returned (See
+    /// [`raw_os_error`][Self::raw_os_error]).
+    SocketCreate {
+        family: i32,
+        style: SocketToAssociation,
+        errno: i32,
+    },
+
+    /// A received message is larger than the maximum size allowed. `size` is the size of the
+    /// complete message and `max` is the maximum size. The message is discarded.
+    MessageTooLarge { size: usize, max: usize },
+
+    /// Dynamic Address Reconfiguration (ASCONF, RFC 5061) was not negotiated with the peer, the
+    /// addresses of an established association cannot be changed.
+    AsconfNotSupported,
+
+    /// The addresses cannot be bound on a socket of the address `family` (`AF_INET` or
+    /// `AF_INET6`), eg. IPv6 addresses on an IPv4 socket or IPv4 addresses on an IPv6 socket that
+    /// is `IPV6_V6ONLY`. `addrs` are the offending addresses.
+    AddressFamilyMismatch {
+        family: i32,
+        addrs: Vec<std::net::SocketAddr>,
+    },
+
+    /// The stream ID `sid` does not fit in the 16 bit SCTP stream IDs.
+    InvalidStreamId { sid: usize },
+
+    /// A Notification of a type not supported by this crate was received. See
+    /// [`UnsupportedNotificationPolicy`].
+    UnsupportedNotification,
+
+    /// Setting the socket option `option` (eg. `SCTP_INITMSG`) failed, when setting a number of
+    /// the options together. `errno` is the OS error returned (See
+    /// [`raw_os_error`][Self::raw_os_error]).
+    SocketOption { option: &'static str, errno: i32 },
+}
+
+impl SctpError {
+    /// Returns the [`SctpError`] wrapped inside the given [`std::io::Error`] if any.
+    pub fn from_io_error(err: &std::io::Error) -> Option<&SctpError> {
+        err.get_ref()?.downcast_ref::<SctpError>()
+    }
+
+    /// Returns the OS error that caused this error, if any.
+    ///
+    /// [`std::io::Error::raw_os_error`] of an [`std::io::Error`] wrapping an [`SctpError`] is
+    /// always `None`, the OS error is obtained using this instead.
+    pub fn raw_os_error(&self) -> Option<i32> {
+        match self {
+            Self::SocketCreate { errno, .. } | Self::SocketOption { errno, .. } => Some(*errno),
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> std::io::ErrorKind {
+        match self {
+            Self::InitMsgAfterConnect => std::io::ErrorKind::InvalidInput,
+            Self::OneToManyOnly => std::io::ErrorKind::Unsupported,
+            Self::SocketCreate { errno, .. } => std::io::Error::from_raw_os_error(*errno).kind(),
+            Self::MessageTooLarge { .. } => std::io::ErrorKind::InvalidData,
+            Self::AsconfNotSupported => std::io::ErrorKind::Unsupported,
+            Self::AddressFamilyMismatch { .. } => std::io::ErrorKind::InvalidInput,
+            Self::InvalidStreamId { .. } => std::io::ErrorKind::InvalidInput,
+            Self::UnsupportedNotification => std::io::ErrorKind::InvalidData,
+            Self::SocketOption { errno, .. } => std::io::Error::from_raw_os_error(*errno).kind(),
+        }
+    }
+}
+
+impl std::fmt::Display for SctpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InitMsgAfterConnect => write!(
+                f,
+                "Initialization parameters have no effect on a connected One to One socket."
+            ),
+            Self::OneToManyOnly => write!(f, "Supported only on the One to Many sockets."),
+            Self::SocketCreate {
+                family,
+                style,
+                errno,
+            } => write!(
+                f,
+                "Creating {:?} SCTP socket of family {} failed: {}",
+                style,
+                family_name(*family),
+                std::io::Error::from_raw_os_error(*errno)
+            ),
+            Self::MessageTooLarge { size, max } => write!(
+                f,
+                "Received message of {} bytes larger than the maximum: {} bytes, discarded.",
+                size, max
+            ),
+            Self::AsconfNotSupported => write!(
+                f,
+                "Dynamic Address Reconfiguration (ASCONF) not supported by the association."
+            ),
+            Self::AddressFamilyMismatch { family, addrs } => write!(
+                f,
+                "Addresses cannot be bound on a socket of family {}: {:?}",
+                family_name(*family),
+                addrs
+            ),
+            Self::InvalidStreamId { sid } => write!(
+                f,
+                "Stream ID {} out of the range of the SCTP stream IDs (0 to 65535).",
+                sid
+            ),
+            Self::UnsupportedNotification => write!(f, "Unsupported Notification received."),
+            Self::SocketOption { option, errno } => write!(
+                f,
+                "Setting `{}` failed: {}",
+                option,
+                std::io::Error::from_raw_os_error(*errno)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SctpError {}
+
+// Stream ID checked to fit in the 16 bit SCTP stream IDs, rather than truncated.
+fn stream_id(sid: usize) -> Result<u16, SctpError> {
+    sid.try_into()
+        .map_err(|_| SctpError::InvalidStreamId { sid })
+}
+
+// Name of the address family for the error messages.
+fn family_name(family: i32) -> String {
+    match family {
+        libc::AF_INET => "AF_INET".to_string(),
+        libc::AF_INET6 => "AF_INET6".to_string(),
+        other => other.to_string(),
+    }
+}
+
+impl From<SctpError> for std::io::Error {
+    fn from(err: SctpError) -> Self {
+        std::io::Error::new(err.kind(), err)
+    }
+}
+
 pub(crate) mod internal;
@@ -4,7 +4,14 @@
 //! performing certain SCTP related functionality using `libc::getsockopt` or `libc::setsockopt`.
 //! Structures below are used by the implementation details and are not part of the public API.
 
-use crate::{AssociationId, Event};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    AssociationId, Event, MessageReassembler, NotificationOrData, ReceivedMessageInfo,
+    SubscribeEventAssocId, UnsupportedNotificationPolicy,
+};
 
 // Structure used by `sctp_peeloff` (Section 9.2)
 #[repr(C)]
@@ -41,13 +48,13 @@ pub(crate) struct GetAddrs {
 #[derive(Debug)]
 pub(crate) struct SubscribeEvent {
     pub(crate) assoc_id: AssociationId,
-    pub(crate) event: Event,
+    pub(crate) event: u16,
     pub(crate) on: bool,
 }
 
 // SCTP Initiation Structure (See Section 5.3.1 of RFC 6458)
 #[repr(C)]
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub(crate) struct InitMsg {
     pub(crate) ostreams: u16,
     pub(crate) istreams: u16,
@@ -78,6 +85,141 @@ pub struct PeerAddrInternal {
     pub mtu: u32,
 }
 
+// PeerAddrParamsInternal: Structure used for `SCTP_PEER_ADDR_PARAMS`.
+//
+// The kernel structure is `packed` and `aligned(4)`, the trailing `pad` makes the size of this
+// structure same as the kernel's, the kernel rejects any other size.
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub struct PeerAddrParamsInternal {
+    pub assoc_id: AssociationId,
+    pub address: libc::sockaddr_storage,
+    pub hb_interval: u32,
+    pub path_max_rxt: u16,
+    pub path_mtu: u32,
+    pub sack_delay: u32,
+    pub flags: u32,
+    pub ipv6_flowlabel: u32,
+    pub dscp: u8,
+    pub pad: u8,
+}
+
+// Structure used for `SCTP_PRIMARY_ADDR` (See Section 8.1.9 of RFC 6458)
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+pub struct SetPrimaryInternal {
+    pub assoc_id: AssociationId,
+    pub address: libc::sockaddr_storage,
+}
+
+// Structure used for `SCTP_GET_PEER_ADDR_INFO` (See Section 8.2.2 of RFC 6458). The kernel
+// structure is packed and aligned to 4 bytes.
+#[repr(C, packed(4))]
+#[derive(Clone, Copy)]
+pub struct PeerAddrInfoInternal {
+    pub assoc_id: AssociationId,
+    pub address: libc::sockaddr_storage,
+    pub state: i32,
+    pub cwnd: u32,
+    pub srtt: u32,
+    pub rto: u32,
+    pub mtu: u32,
+}
+
+// Structure used for `SCTP_REMOTE_UDP_ENCAPS_PORT` (See RFC 6951). `port` is in the network byte
+// order.
+#[cfg(target_os = "linux")]
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct UdpEncapsInternal {
+    pub assoc_id: AssociationId,
+    pub address: libc::sockaddr_storage,
+    pub port: u16,
+}
+
+// Structure used for `SCTP_AUTH_ACTIVE_KEY` (See Section 8.3.3 of RFC 6458). `SCTP_AUTH_KEY`
+// uses a variable length structure, see `sctp_set_shared_key_internal`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct AuthKeyId {
+    pub assoc_id: AssociationId,
+    pub key_id: u16,
+}
+
+// Structure `sctp_sndrcvinfo` used by the `SCTP_SNDRCV` ancillary data (See Section 5.3.2 of RFC
+// 6458). Deprecated in favor of `SCTP_SNDINFO`, but it is the only one carrying the time to live
+// of a message, which is what `sctp_sendmsg` uses.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct SndRcvInfo {
+    pub(crate) stream: u16,
+    pub(crate) ssn: u16,
+    pub(crate) flags: u16,
+    pub(crate) ppid: u32,
+    pub(crate) context: u32,
+    pub(crate) timetolive: u32,
+    pub(crate) tsn: u32,
+    pub(crate) cumtsn: u32,
+    pub(crate) assoc_id: AssociationId,
+}
+
+// Structure `sctp_setadaptation` used for `SCTP_ADAPTATION_LAYER` (See Section 8.1.10 of RFC 6458)
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct SetAdaptation {
+    pub(crate) adaptation_ind: u32,
+}
+
+// Structure `sctp_default_prinfo` used for `SCTP_DEFAULT_PRINFO` (See Section 4 of RFC 7496). The
+// field order is the one of Linux, which differs from the one in the RFC.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct DefaultPrInfo {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) value: u32,
+    pub(crate) policy: u16,
+}
+
+// Structure `sctp_sack_info` used for `SCTP_DELAYED_SACK` (See Section 8.1.19 of RFC 6458)
+#[repr(C)]
+#[derive(Debug, Default)]
+pub(crate) struct SackInfo {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) sack_delay: u32,
+    pub(crate) sack_freq: u32,
+}
+
+// Structure `sctp_assoc_stats` used for `SCTP_GET_ASSOC_STATS` (Linux specific)
+#[repr(C)]
+pub(crate) struct AssocStats {
+    pub(crate) assoc_id: AssociationId,
+    pub(crate) obs_rto_ipaddr: libc::sockaddr_storage,
+    pub(crate) maxrto: u64,
+    pub(crate) isacks: u64,
+    pub(crate) osacks: u64,
+    pub(crate) opackets: u64,
+    pub(crate) ipackets: u64,
+    pub(crate) rtxchunks: u64,
+    pub(crate) outofseqtsns: u64,
+    pub(crate) idupchunks: u64,
+    pub(crate) gapcnt: u64,
+    pub(crate) ouodchunks: u64,
+    pub(crate) iuodchunks: u64,
+    pub(crate) oodchunks: u64,
+    pub(crate) iodchunks: u64,
+    pub(crate) octrlchunks: u64,
+    pub(crate) ictrlchunks: u64,
+}
+
+// Structure `sctp_assoc_value` used for a number of the socket options that take a single value
+// per association (eg. `SCTP_MAXSEG`, See Section 8.1.16 of RFC 6458)
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct AssocValue {
+    pub assoc_id: AssociationId,
+    pub assoc_value: u32,
+}
+
 // ConnStatusInternal: Status of an SCTP Connection
 #[repr(C)]
 #[derive(Clone)]
@@ -94,10 +236,11 @@ pub struct ConnStatusInternal {
 }
 
 use std::convert::{TryFrom, TryInto};
+use std::net::SocketAddr;
 
 use os_socketaddr::OsSocketAddr;
 
-use crate::types::{ConnState, ConnStatus, PeerAddress};
+use crate::types::{ConnState, ConnStatus, PeerAddrParams, PeerAddress};
 
 impl TryFrom<PeerAddrInternal> for PeerAddress {
     type Error = std::io::Error;
@@ -138,11 +281,9 @@ impl TryFrom<PeerAddrInternal> for PeerAddress {
     }
 }
 
-impl TryFrom<ConnStatusInternal> for ConnStatus {
-    type Error = std::convert::Infallible;
-
-    fn try_from(val: ConnStatusInternal) -> Result<Self, Self::Error> {
-        Ok(Self {
+impl From<ConnStatusInternal> for ConnStatus {
+    fn from(val: ConnStatusInternal) -> Self {
+        Self {
             assoc_id: val.assoc_id,
             state: ConnState::from_i32(val.state),
             rwnd: val.rwnd,
@@ -152,6 +293,369 @@ impl TryFrom<ConnStatusInternal> for ConnStatus {
             outstreams: val.outstreams,
             fragmentation_pt: val.fragmentation_pt,
             peer_primary: val.peer_primary.try_into().unwrap(),
-        })
+        }
+    }
+}
+
+impl From<PeerAddrParams> for PeerAddrParamsInternal {
+    fn from(val: PeerAddrParams) -> Self {
+        Self {
+            assoc_id: val.assoc_id,
+            address: sockaddr_storage_from(val.address),
+            hb_interval: val.hb_interval,
+            path_max_rxt: val.path_max_rxt,
+            path_mtu: val.path_mtu,
+            sack_delay: val.sack_delay,
+            flags: val.flags,
+            ipv6_flowlabel: val.ipv6_flowlabel,
+            dscp: val.dscp,
+            pad: 0,
+        }
+    }
+}
+
+impl From<PeerAddrParamsInternal> for PeerAddrParams {
+    fn from(val: PeerAddrParamsInternal) -> Self {
+        let address = val.address;
+        // Safety: `address` is valid for the size of `sockaddr_storage`, for any family other than
+        // `AF_INET` or `AF_INET6` (eg. `AF_UNSPEC`), `None` is returned.
+        let address = unsafe {
+            OsSocketAddr::copy_from_raw(
+                &address as *const _ as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_storage>()
+                    .try_into()
+                    .unwrap(),
+            )
+        }
+        .into_addr();
+        Self {
+            assoc_id: val.assoc_id,
+            address,
+            hb_interval: val.hb_interval,
+            path_max_rxt: val.path_max_rxt,
+            path_mtu: val.path_mtu,
+            sack_delay: val.sack_delay,
+            flags: val.flags,
+            ipv6_flowlabel: val.ipv6_flowlabel,
+            dscp: val.dscp,
+        }
+    }
+}
+
+// Get the `sockaddr_storage` for the given address. An all zeroes (`AF_UNSPEC`) address is
+// returned for `None`.
+pub(crate) fn sockaddr_storage_from(addr: Option<SocketAddr>) -> libc::sockaddr_storage {
+    // Safety: An all zeroes `sockaddr_storage` is a valid (`AF_UNSPEC`) address.
+    let mut address: libc::sockaddr_storage = unsafe { std::mem::zeroed() };
+    if let Some(addr) = addr {
+        let os_socketaddr: OsSocketAddr = addr.into();
+        // Safety: `os_socketaddr` is valid and it's length is never more than the size of
+        // `sockaddr_storage`.
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                os_socketaddr.as_ptr() as *const u8,
+                &mut address as *mut _ as *mut u8,
+                os_socketaddr.len() as usize,
+            );
+        }
+    }
+    address
+}
+
+// Shared keys installed on a socket per Association ID. The kernel does not provide a way to list
+// the installed keys, hence they are tracked as they are installed. The keys of the Assoc ID `0`
+// are the keys of the endpoint, which the kernel copies to every new association. The key `0`
+// (the null key) is always installed by the kernel.
+#[derive(Debug)]
+pub(crate) struct AuthKeys(Mutex<HashMap<AssociationId, BTreeSet<u16>>>);
+
+impl Default for AuthKeys {
+    fn default() -> Self {
+        Self::with_endpoint_keys(BTreeSet::from([0]))
+    }
+}
+
+impl AuthKeys {
+    fn with_endpoint_keys(keys: BTreeSet<u16>) -> Self {
+        Self(Mutex::new(HashMap::from([(
+            AssociationId::default(),
+            keys,
+        )])))
+    }
+
+    // Keys installed for the association, associations that no keys are installed for explicitly
+    // have the keys of the endpoint.
+    pub(crate) fn installed(&self, assoc_id: AssociationId) -> Vec<u16> {
+        let keys = self.0.lock().unwrap();
+        keys.get(&assoc_id)
+            .or_else(|| keys.get(&AssociationId::default()))
+            .map_or_else(Vec::new, |keys| keys.iter().copied().collect())
+    }
+
+    pub(crate) fn insert(&self, assoc_id: AssociationId, key_id: u16) {
+        let mut keys = self.0.lock().unwrap();
+        if assoc_id == SubscribeEventAssocId::Current.into()
+            || assoc_id == SubscribeEventAssocId::All.into()
+        {
+            for (id, keys) in keys.iter_mut() {
+                if !id.is_sentinel() || assoc_id == SubscribeEventAssocId::All.into() {
+                    keys.insert(key_id);
+                }
+            }
+        } else {
+            let endpoint_keys = keys
+                .get(&AssociationId::default())
+                .cloned()
+                .unwrap_or_default();
+            keys.entry(assoc_id).or_insert(endpoint_keys).insert(key_id);
+        }
+    }
+
+    // Keys of a socket for the association (eg. accepted or peeled off), that inherits the keys
+    // of the given association.
+    pub(crate) fn for_association(&self, assoc_id: AssociationId) -> Self {
+        Self::with_endpoint_keys(self.installed(assoc_id).into_iter().collect())
+    }
+}
+
+// Stream Sequence Numbers (SSN) assigned to the ordered messages sent per association and stream.
+// The kernel does not report back the SSN assigned to a sent message, hence the messages are
+// counted as they are sent. The first ordered message on a stream has the SSN `0`.
+#[derive(Debug, Default)]
+pub(crate) struct SentSsns(Mutex<HashMap<(AssociationId, u16), u16>>);
+
+impl SentSsns {
+    // Returns the SSN assigned to the message just sent on the stream of the association.
+    pub(crate) fn next(&self, assoc_id: AssociationId, sid: u16) -> u16 {
+        let mut ssns = self.0.lock().unwrap();
+        let next_ssn = ssns.entry((assoc_id, sid)).or_insert(0);
+        let ssn = *next_ssn;
+        *next_ssn = ssn.wrapping_add(1);
+        ssn
+    }
+}
+
+// Events that are subscribed to again for an association, when it is restarted. See
+// `set_resubscribe_on_restart`.
+#[derive(Debug, Default)]
+pub(crate) struct ResubscribeEvents(Mutex<Vec<Event>>);
+
+impl ResubscribeEvents {
+    pub(crate) fn set(&self, events: &[Event]) {
+        *self.0.lock().unwrap() = events.to_vec();
+    }
+
+    pub(crate) fn get(&self) -> Vec<Event> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+// Whether the last send failed with `WouldBlock`, see `last_send_blocked`.
+#[derive(Debug, Default)]
+pub(crate) struct SendBlocked(AtomicBool);
+
+impl SendBlocked {
+    pub(crate) fn set(&self, blocked: bool) {
+        self.0.store(blocked, Ordering::Relaxed);
+    }
+
+    pub(crate) fn get(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// What `sctp_recv` does when an unsupported Notification is received, see
+// `set_unsupported_notification_policy`.
+#[derive(Debug)]
+pub(crate) struct NotificationPolicy(Mutex<UnsupportedNotificationPolicy>);
+
+impl Default for NotificationPolicy {
+    fn default() -> Self {
+        Self(Mutex::new(UnsupportedNotificationPolicy::Return))
+    }
+}
+
+impl NotificationPolicy {
+    pub(crate) fn set(&self, policy: UnsupportedNotificationPolicy) {
+        *self.0.lock().unwrap() = policy;
+    }
+
+    pub(crate) fn get(&self) -> UnsupportedNotificationPolicy {
+        *self.0.lock().unwrap()
+    }
+}
+
+// Filter of the peers whose connections are accepted, see `set_accept_filter`. All the peers are
+// accepted when no filter is set. The filter is called outside of the lock, so that it can set a
+// new filter itself.
+type AcceptFilterFn = Arc<dyn Fn(SocketAddr) -> bool + Send + Sync>;
+
+#[derive(Default)]
+pub(crate) struct AcceptFilter(Mutex<Option<AcceptFilterFn>>);
+
+impl AcceptFilter {
+    pub(crate) fn set(&self, filter: AcceptFilterFn) {
+        *self.0.lock().unwrap() = Some(filter);
+    }
+
+    pub(crate) fn accepts(&self, addr: SocketAddr) -> bool {
+        let filter = self.0.lock().unwrap().clone();
+        match filter {
+            Some(filter) => filter(addr),
+            None => true,
+        }
+    }
+}
+
+impl std::fmt::Debug for AcceptFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let filter = self
+            .0
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|_| "Fn(SocketAddr) -> bool");
+        f.debug_tuple("AcceptFilter").field(&filter).finish()
+    }
+}
+
+// Parts of the Data messages received so far, when receiving the complete messages. See
+// `recv_coalesced` and `set_reassembly`, the reassembly is enabled by default.
+#[derive(Debug)]
+pub(crate) struct PartialMessages {
+    reassembler: Mutex<MessageReassembler>,
+    enabled: AtomicBool,
+}
+
+impl Default for PartialMessages {
+    fn default() -> Self {
+        Self {
+            reassembler: Mutex::new(MessageReassembler::new()),
+            enabled: AtomicBool::new(true),
+        }
+    }
+}
+
+impl PartialMessages {
+    // Returns the Notification or the complete Data message, `None` if more parts of the message
+    // are to be received. The message size is limited to `max` if given, instead of the maximum
+    // size set for the reassembler.
+    pub(crate) fn received(
+        &self,
+        received: NotificationOrData,
+        eor: bool,
+        max: Option<usize>,
+    ) -> std::io::Result<Option<NotificationOrData>> {
+        let mut reassembler = self.reassembler.lock().unwrap();
+        match received {
+            NotificationOrData::Notification(notification) => {
+                reassembler.notification(&notification);
+                Ok(Some(NotificationOrData::Notification(notification)))
+            }
+            NotificationOrData::Data(data) => {
+                let configured = reassembler.max_message_size();
+                reassembler.set_max_message_size(max.unwrap_or(configured));
+                let result = reassembler.push(data, eor);
+                reassembler.set_max_message_size(configured);
+
+                Ok(result?.map(NotificationOrData::Data))
+            }
+        }
+    }
+
+    pub(crate) fn reset(&self, assoc_id: AssociationId, stream: u16) {
+        self.reassembler.lock().unwrap().reset(assoc_id, stream);
+    }
+
+    pub(crate) fn set_max_message_size(&self, max: usize) {
+        self.reassembler.lock().unwrap().set_max_message_size(max);
+    }
+
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_enabled(&self, on: bool) {
+        self.enabled.store(on, Ordering::Relaxed);
+    }
+}
+
+// How the Data messages are reassembled by a receive call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Reassembly {
+    // Only if the reassembly is turned on for the socket.
+    Configured,
+
+    // Always, up to the maximum message size set for the socket.
+    Always,
+
+    // Always, up to the given maximum message size.
+    Bounded(usize),
+}
+
+// State of the receive side of a socket. All the APIs receiving on a socket (including the
+// `Incoming` and the `NotificationQueue` adaptors) use the same state, so that the socket behaves
+// the same whichever API is used.
+#[derive(Debug, Default)]
+pub(crate) struct ReceiveState {
+    pub(crate) resubscribe: ResubscribeEvents,
+    pub(crate) policy: NotificationPolicy,
+    pub(crate) partial: PartialMessages,
+    pub(crate) pending: PendingMessages,
+}
+
+// The messages received from the socket by an API that does not return them to the caller (like
+// `send_confirmed` waiting for the `SENDER_DRY` event), held as received, to be returned by the
+// next receive calls in the order they were received.
+#[derive(Debug, Default)]
+pub(crate) struct PendingMessages(Mutex<VecDeque<(ReceivedMessageInfo, Vec<u8>)>>);
+
+impl PendingMessages {
+    pub(crate) fn push(&self, info: ReceivedMessageInfo, recv_buffer: Vec<u8>) {
+        self.0.lock().unwrap().push_back((info, recv_buffer));
+    }
+
+    pub(crate) fn pop(&self) -> Option<(ReceivedMessageInfo, Vec<u8>)> {
+        self.0.lock().unwrap().pop_front()
+    }
+}
+
+impl ReceiveState {
+    // The state of a socket, whose messages are not reassembled.
+    pub(crate) fn without_reassembly() -> Self {
+        let state = Self::default();
+        state.partial.set_enabled(false);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accept_filter_replaced_by_itself() {
+        let accept_filter = Arc::new(AcceptFilter::default());
+        assert!(accept_filter.accepts("127.0.0.1:8080".parse().unwrap()));
+
+        // A filter that rejects the first peer and replaces itself to accept the later ones.
+        let replaced = Arc::clone(&accept_filter);
+        accept_filter.set(Arc::new(move |_| {
+            replaced.set(Arc::new(|_| true));
+            false
+        }));
+        assert!(!accept_filter.accepts("127.0.0.1:8080".parse().unwrap()));
+        assert!(accept_filter.accepts("127.0.0.1:8080".parse().unwrap()));
+    }
+
+    #[test]
+    fn default_pr_info_layout() {
+        let pr_info = DefaultPrInfo::default();
+        let base = std::ptr::addr_of!(pr_info) as usize;
+
+        assert_eq!(std::ptr::addr_of!(pr_info.assoc_id) as usize - base, 0);
+        assert_eq!(std::ptr::addr_of!(pr_info.value) as usize - base, 4);
+        assert_eq!(std::ptr::addr_of!(pr_info.policy) as usize - base, 8);
+        assert_eq!(std::mem::size_of::<DefaultPrInfo>(), 12);
     }
 }
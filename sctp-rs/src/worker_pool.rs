@@ -0,0 +1,109 @@
+//! Handling every association of a One to Many socket in its own worker.
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use crate::{
+    AssocChangeState, AssociationChange, AssociationId, Event, Notification, NotificationOrData,
+    OneToManySocket, OneToOneSocket, SubscribeEventAssocId,
+};
+
+/// A server that peels off every association of a [`OneToManySocket`] and hands the resulting
+/// [`OneToOneSocket`] to a worker.
+///
+/// This is a common server architecture, where a single One to Many socket accepts the
+/// associations, but each of the associations is handled independently of the others. The
+/// `worker` is called for every association and the returned future is spawned as a separate
+/// task on the `tokio` runtime, so the workers run on the runtime's thread pool.
+///
+/// ```rust,no_run
+/// # async fn serve() -> std::io::Result<()> {
+/// use sctp_rs::{OneToManySocket, WorkerPool};
+///
+/// let socket = OneToManySocket::listen_v4(&["127.0.0.1:8080".parse().unwrap()], 10)?;
+/// let pool = WorkerPool::new(socket, |assoc: sctp_rs::OneToOneSocket| async move {
+///     while let Ok(received) = assoc.sctp_recv().await {
+///         // Process the Notification or Data.
+///     }
+/// });
+/// pool.run(|received| {
+///     // Process the Notification or Data received before the association is peeled off.
+/// })
+/// .await
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WorkerPool<F> {
+    socket: OneToManySocket,
+    worker: F,
+}
+
+impl<F, Fut> WorkerPool<F>
+where
+    F: Fn(OneToOneSocket) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    /// Create a new [`WorkerPool`] handing the associations of the `socket` to the `worker`.
+    pub fn new(socket: OneToManySocket, worker: F) -> Self {
+        Self { socket, worker }
+    }
+
+    /// Run the pool.
+    ///
+    /// Subscribes to the [`Association`][Event::Association] events and peels off every new
+    /// association, when its `CommUp` notification is received. Associations that are already
+    /// present on the socket when the pool is run are peeled off right away. Everything else
+    /// received on the socket itself, like the data received on an association before it is
+    /// peeled off, is passed to the `unhandled` callback.
+    ///
+    /// This runs until receiving on the socket fails, in which case the error is returned. The
+    /// workers that are already spawned keep running.
+    pub async fn run<H>(&self, mut unhandled: H) -> std::io::Result<()>
+    where
+        H: FnMut(NotificationOrData),
+    {
+        self.socket
+            .sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::All)?;
+
+        // The associations peeled off right away, whose `CommUp` may still be queued.
+        let mut present = HashSet::new();
+        for assoc_id in self.socket.assoc_ids()? {
+            self.dispatch(assoc_id);
+            present.insert(assoc_id);
+        }
+
+        loop {
+            match self.socket.sctp_recv().await? {
+                NotificationOrData::Notification(Notification::AssociationChange(
+                    AssociationChange {
+                        state: AssocChangeState::CommUp,
+                        assoc_id,
+                        ..
+                    },
+                )) if present.remove(&assoc_id) => {
+                    log::debug!("Association: {} is already peeled off.", assoc_id)
+                }
+                NotificationOrData::Notification(Notification::AssociationChange(
+                    AssociationChange {
+                        state: AssocChangeState::CommUp,
+                        assoc_id,
+                        ..
+                    },
+                )) => self.dispatch(assoc_id),
+                other => unhandled(other),
+            }
+        }
+    }
+
+    // Peel off the association and spawn the worker for it. An association that cannot be peeled
+    // off (eg. already closed or already peeled off) is skipped.
+    fn dispatch(&self, assoc_id: AssociationId) {
+        match self.socket.sctp_peeloff(assoc_id) {
+            Ok(socket) => {
+                log::debug!("Spawning worker for the association: {}", assoc_id);
+                tokio::spawn((self.worker)(socket));
+            }
+            Err(e) => log::error!("Error: {} peeling off association: {}", e, assoc_id),
+        }
+    }
+}
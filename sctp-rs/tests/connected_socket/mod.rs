@@ -1,6 +1,6 @@
 use sctp_rs::*;
 
-use crate::{create_client_socket, create_socket_bind_and_listen};
+use crate::{create_client_socket, create_socket_bind_and_listen, TEST_PORT_NO};
 
 #[tokio::test]
 async fn bindx_not_supported() {
@@ -34,7 +34,7 @@ async fn connected_default_sendinfo_success() {
         sid,
         ppid,
         flags: 0,
-        assoc_id: 0,
+        assoc_id: 0.into(),
         context: 0,
     };
 
@@ -44,6 +44,7 @@ async fn connected_default_sendinfo_success() {
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: None,
+        auth_key_id: None,
     };
     let result = accepted.sctp_send(senddata.clone()).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -87,7 +88,7 @@ async fn connected_default_sendinfo_success() {
         );
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 
@@ -115,13 +116,14 @@ async fn connected_send_some_sendinfo_success() {
         sid,
         ppid,
         flags: 1,
-        assoc_id: 0,
+        assoc_id: 0.into(),
         context: 0,
     };
 
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: Some(snd_info),
+        auth_key_id: None,
     };
     let result = accepted.sctp_send(senddata).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -165,7 +167,7 @@ async fn connected_send_some_sendinfo_success() {
         );
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 #[tokio::test]
@@ -210,7 +212,7 @@ async fn test_shutdown_event() {
             client_assoc_id, assoc_id
         );
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     }
 }
 
@@ -230,7 +232,7 @@ async fn test_get_status() {
     assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
     let (accepted, client_addr) = accept.unwrap();
 
-    let result = connected.sctp_get_status(0);
+    let result = connected.sctp_get_status(0.into());
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
     let status = result.unwrap();
     assert_eq!(
@@ -239,7 +241,7 @@ async fn test_get_status() {
         client_assoc_id, status.assoc_id
     );
 
-    let result = accepted.sctp_get_status(0);
+    let result = accepted.sctp_get_status(0.into());
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
     let status = result.unwrap();
     assert_eq!(
@@ -248,3 +250,1595 @@ async fn test_get_status() {
         client_addr, status.peer_primary.address
     );
 }
+
+#[tokio::test]
+async fn test_enable_all_events() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.enable_all_events(SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    // drop the accepted socket, so that the association is shutdown.
+    drop(accepted);
+
+    let mut notifications = vec![];
+    loop {
+        let result = connected.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let data = result.unwrap();
+        if let NotificationOrData::Notification(notification) = data {
+            let done = matches!(
+                notification,
+                Notification::AssociationChange(AssociationChange {
+                    state: AssocChangeState::ShutdownComplete,
+                    ..
+                })
+            );
+            notifications.push(notification);
+            if done {
+                break;
+            }
+        } else {
+            panic!("Should never come here!: {:#?}", data);
+        }
+    }
+
+    assert!(
+        notifications.iter().any(|n| matches!(
+            n,
+            Notification::AssociationChange(AssociationChange {
+                state: AssocChangeState::CommUp,
+                ..
+            })
+        )),
+        "{:#?}",
+        notifications
+    );
+    assert!(
+        notifications
+            .iter()
+            .any(|n| matches!(n, Notification::Shutdown(Shutdown { .. }))),
+        "{:#?}",
+        notifications
+    );
+}
+
+#[tokio::test]
+async fn connected_set_get_default_sndinfo_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let sid = 3;
+    let ppid = 0x4321;
+    let context = 42;
+    let sendinfo = SendInfo {
+        sid,
+        ppid,
+        flags: 0,
+        assoc_id: 0.into(),
+        context,
+    };
+
+    let result = accepted.set_default_sndinfo(sendinfo, 0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.get_default_sndinfo(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let defaults = result.unwrap();
+    assert_eq!(defaults.sid, sid, "{:#?}", defaults);
+    assert_eq!(defaults.ppid, ppid, "{:#?}", defaults);
+    assert_eq!(defaults.context, context, "{:#?}", defaults);
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = accepted.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let data = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { rcv_info, .. }) = data {
+        assert!(rcv_info.is_some());
+        let rcv_info = rcv_info.unwrap();
+        assert_eq!(rcv_info.sid, sid, "{:#?}", rcv_info);
+        assert_eq!(rcv_info.ppid, ppid, "{:#?}", rcv_info);
+    } else {
+        panic!("Should never come here!: {:#?}", data);
+    }
+}
+
+#[tokio::test]
+async fn connected_set_heartbeat_interval_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let interval = std::time::Duration::from_millis(5000);
+    let result = connected.set_heartbeat_interval(0.into(), interval);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_get_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let primary = result.unwrap().peer_primary.address;
+
+    let result = connected.peer_addr_params(0.into(), Some(primary));
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let params = result.unwrap();
+    assert_eq!(params.hb_interval, 5000, "{:#?}", params);
+}
+
+#[tokio::test]
+async fn connected_set_primary_addr_made_prim() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let unknown: std::net::SocketAddr = "127.0.0.2:1".parse().unwrap();
+    let result = connected.set_primary_addr(0.into(), unknown);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    let result = connected.sctp_subscribe_events(&[Event::Address], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.set_primary_addr(0.into(), bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    loop {
+        let result = connected.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let received = result.unwrap();
+        if let NotificationOrData::Notification(Notification::PeerAddrChange(PeerAddrChange {
+            address,
+            state,
+            ..
+        })) = received
+        {
+            if state == PeerAddrState::MadePrim {
+                assert_eq!(address, bindaddr);
+                break;
+            }
+        } else {
+            panic!("Should never come here!: {:#?}", received);
+        }
+    }
+}
+
+#[tokio::test]
+async fn connected_send_zero_length_failure() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let senddata = SendData {
+        payload: vec![],
+        snd_info: Some(SendInfo {
+            sid: 0,
+            ..Default::default()
+        }),
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn connected_fragmentation_point_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.fragmentation_point(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let fragmentation_point = result.unwrap();
+
+    let result = connected.sctp_get_status(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let mtu = result.unwrap().peer_primary.mtu;
+    assert!(
+        fragmentation_point > 0 && fragmentation_point < mtu,
+        "fragmentation point: {}, path MTU: {}",
+        fragmentation_point,
+        mtu
+    );
+}
+
+#[tokio::test]
+async fn connected_incoming_until_shutdown() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let result = listener.sctp_subscribe_events(&[Event::Shutdown], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let senddata = SendData {
+        payload: b"hello".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.shutdown(std::net::Shutdown::Write);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let mut received = vec![];
+    let mut incoming = accepted.incoming();
+    while let Some(result) = incoming.next().await {
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        received.push(result.unwrap());
+    }
+    assert!(incoming.next().await.is_none());
+
+    assert_eq!(received.len(), 2, "{:#?}", received);
+    assert!(
+        matches!(received[0], NotificationOrData::Data(_)),
+        "{:#?}",
+        received
+    );
+    assert!(
+        matches!(
+            received[1],
+            NotificationOrData::Notification(Notification::Shutdown(_))
+        ),
+        "{:#?}",
+        received
+    );
+}
+
+#[tokio::test]
+async fn connected_setup_init_params_after_connect_failure() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_setup_init_params(10, 10, 0, 0);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::InitMsgAfterConnect),
+        "{:#?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn connected_send_authenticated_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    // Require `DATA` chunks to be authenticated and use the same shared key on both the sides.
+    // Needs `net.sctp.auth_enable` to be set.
+    let result = listener.set_auth_chunk(0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = listener.set_shared_key(0.into(), 1, b"shared secret");
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = client_socket.set_auth_chunk(0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = client_socket.set_shared_key(0.into(), 1, b"shared secret");
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = connected.activate_key(0.into(), 1);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: Some(1),
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    match received {
+        NotificationOrData::Data(ReceivedData { payload, .. }) => {
+            assert_eq!(payload, b"hello world!".to_vec());
+        }
+        _ => panic!("Should never come here!: {:#?}", received),
+    }
+}
+
+#[tokio::test]
+async fn connected_send_confirmed_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _client_addr) = accept.unwrap();
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected
+        .send_confirmed(senddata, std::time::Duration::from_secs(1))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(
+        result.unwrap(),
+        "Delivery not confirmed within the timeout."
+    );
+}
+
+#[tokio::test]
+async fn connected_send_confirmed_keeps_received_data() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    // Data from the peer that is received while waiting for the `SENDER_DRY` event.
+    let senddata = SendData {
+        payload: b"hello client!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = accepted.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected
+        .send_confirmed(senddata, std::time::Duration::from_secs(1))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(
+        result.unwrap(),
+        "Delivery not confirmed within the timeout."
+    );
+
+    let received = connected.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        NotificationOrData::Data(ReceivedData { payload, .. }) => {
+            assert_eq!(payload, b"hello client!".to_vec());
+        }
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+#[tokio::test]
+async fn connected_send_confirmed_stale_sender_dry() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result =
+        connected.sctp_subscribe_events(&[Event::SenderDry], SubscribeEventAssocId::Current);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // Leave the `SENDER_DRY` event of an earlier send queued on the socket.
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let senddata = SendData {
+        payload: b"hello again!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected
+        .send_confirmed(senddata, std::time::Duration::from_secs(1))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(
+        result.unwrap(),
+        "Delivery not confirmed within the timeout."
+    );
+
+    let status = connected.sctp_get_status(0.into());
+    assert!(status.is_ok(), "{:#?}", status.err().unwrap());
+    let status = status.unwrap();
+    assert_eq!(status.unacked_data, 0);
+    assert_eq!(status.pending_data, 0);
+}
+
+#[tokio::test]
+async fn connected_split_send_recv_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let (sender, receiver) = accepted.split();
+
+    // Echo back on the sender half what's received on the receiver half.
+    let echo = tokio::spawn(async move {
+        let received = receiver.sctp_recv().await?;
+        if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+            sender
+                .sctp_send(SendData {
+                    payload,
+                    snd_info: None,
+                    auth_key_id: None,
+                })
+                .await?;
+        }
+        Ok::<(), std::io::Error>(())
+    });
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    match received {
+        NotificationOrData::Data(ReceivedData { payload, .. }) => {
+            assert_eq!(payload, b"hello world!".to_vec());
+        }
+        _ => panic!("Should never come here!: {:#?}", received),
+    }
+
+    let result = echo.await.unwrap();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn connected_disable_fragments_large_send_failure() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.set_disable_fragments(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = connected.disable_fragments();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let result = connected.fragmentation_point(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let fragmentation_pt = result.unwrap() as usize;
+
+    let senddata = SendData {
+        payload: vec![0u8; fragmentation_pt + 1],
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(
+        result.is_err(),
+        "Sending a message larger than fragmentation point succeeded."
+    );
+    assert_eq!(result.err().unwrap().raw_os_error(), Some(libc::EMSGSIZE));
+}
+
+#[tokio::test]
+async fn connected_set_dscp_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    // Expedited Forwarding for all the paths of the association.
+    let any: std::net::SocketAddr = "0.0.0.0:0".parse().unwrap();
+    let result = connected.set_dscp(0.into(), any, 46);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.peer_addr_params(0.into(), Some(bindaddr));
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let params = result.unwrap();
+    assert_eq!(params.dscp, 46 << 2, "{:#?}", params);
+
+    // Not a 6 bit code point.
+    let result = connected.set_dscp(0.into(), bindaddr, 64);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn connected_probe_all_paths_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.probe_all_paths(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn connected_notification_queue_drain_separately() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result =
+        client_socket.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    for payload in [b"first", b"other"] {
+        let result = accepted
+            .sctp_send(SendData {
+                payload: payload.to_vec(),
+                snd_info: None,
+                auth_key_id: None,
+            })
+            .await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let mut queue = connected.notification_queue();
+
+    // The `CommUp` notification received before the data is queued.
+    let result = queue.next_data().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().payload, b"first".to_vec());
+    assert_eq!(queue.queued_notifications(), 1);
+
+    let result = queue.next_data().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().payload, b"other".to_vec());
+
+    let result = queue.next_notification().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    assert!(
+        matches!(
+            notification,
+            Notification::AssociationChange(AssociationChange {
+                state: AssocChangeState::CommUp,
+                ..
+            })
+        ),
+        "{:#?}",
+        notification
+    );
+    assert_eq!(queue.queued_notifications(), 0);
+}
+
+#[tokio::test]
+async fn connected_recv_rcvinfo_nxtinfo_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _) = accept.unwrap();
+
+    let result = accepted.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = accepted.sctp_request_nxtinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    for (sid, ppid) in [(1_u16, 0x1234_u32), (2, 0x5678)] {
+        let result = connected
+            .sctp_send(SendData {
+                payload: b"hello world!".to_vec(),
+                snd_info: Some(SendInfo {
+                    sid,
+                    ppid,
+                    flags: 0,
+                    assoc_id: 0.into(),
+                    context: 0,
+                }),
+                auth_key_id: None,
+            })
+            .await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    // Wait for both the messages to arrive, so that the `NxtInfo` for the second message is
+    // available while receiving the first.
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    match received {
+        NotificationOrData::Data(ReceivedData {
+            rcv_info: Some(ref rcv_info),
+            nxt_info: Some(ref nxt_info),
+            ..
+        }) => {
+            assert_eq!(
+                (rcv_info.sid, rcv_info.ppid),
+                (1, 0x1234),
+                "{:#?}",
+                rcv_info
+            );
+            assert_eq!(
+                (nxt_info.sid, nxt_info.ppid),
+                (2, 0x5678),
+                "{:#?}",
+                nxt_info
+            );
+        }
+        _ => panic!("Should never come here!: {:#?}", received),
+    }
+}
+
+#[tokio::test]
+async fn connected_recv_message_bounded_too_large() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    // Larger than the receive buffer, so received in multiple parts.
+    let senddata = SendData {
+        payload: vec![1_u8; 10000],
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = accepted.recv_message_bounded(8192).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::MessageTooLarge {
+            size: 10000,
+            max: 8192
+        }),
+        "{:#?}",
+        err
+    );
+
+    // The oversized message is discarded entirely, the next message is received as is.
+    let result = accepted.recv_message_bounded(8192).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    match result.unwrap() {
+        NotificationOrData::Data(ReceivedData { payload, .. }) => {
+            assert_eq!(payload, b"hello world!".to_vec());
+        }
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+#[tokio::test]
+async fn connected_sctp_recv_reassembles_by_default() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    // Larger than the receive buffer, so received in multiple parts.
+    for payload in [
+        vec![1_u8; 10000],
+        vec![2_u8; 20000],
+        b"hello world!".to_vec(),
+    ] {
+        let senddata = SendData {
+            payload,
+            snd_info: None,
+            auth_key_id: None,
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    match received {
+        NotificationOrData::Data(ref data) => assert_eq!(data.payload, vec![1_u8; 10000]),
+        _ => panic!("Should never come here!: {:#?}", received),
+    }
+
+    accepted.set_max_message_size(16384);
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::MessageTooLarge {
+            size: 20000,
+            max: 16384
+        }),
+        "{:#?}",
+        err
+    );
+
+    let result = accepted.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    match received {
+        NotificationOrData::Data(ref data) => assert_eq!(data.payload, b"hello world!".to_vec()),
+        _ => panic!("Should never come here!: {:#?}", received),
+    }
+}
+
+#[tokio::test]
+async fn connected_incoming_and_notification_queue_reassemble() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    // Larger than the receive buffer, so received in multiple parts.
+    for payload in [vec![1_u8; 10000], vec![2_u8; 20000]] {
+        let senddata = SendData {
+            payload,
+            snd_info: None,
+            auth_key_id: None,
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let mut incoming = accepted.incoming();
+    let result = incoming.next().await;
+    assert!(result.is_some());
+    let result = result.unwrap();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    match received {
+        NotificationOrData::Data(ref data) => assert_eq!(data.payload, vec![1_u8; 10000]),
+        _ => panic!("Should never come here!: {:#?}", received),
+    }
+
+    let mut queue = accepted.notification_queue();
+    let result = queue.next_data().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().payload, vec![2_u8; 20000]);
+}
+
+#[tokio::test]
+async fn connected_bindx_add_address_asconf() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = accepted.sctp_subscribe_events(&[Event::Address], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let added: std::net::SocketAddr = "127.0.0.2:0".parse().unwrap();
+    let result = connected.sctp_bindx(&[added], BindxFlags::Add);
+    if let Err(ref e) = result {
+        // `ASCONF` is disabled by default on Linux (`net.sctp.addip_enable`).
+        assert_eq!(
+            SctpError::from_io_error(e),
+            Some(&SctpError::AsconfNotSupported),
+            "{:#?}",
+            e
+        );
+        return;
+    }
+
+    loop {
+        let received = accepted.sctp_recv().await;
+        assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+        match received.unwrap() {
+            NotificationOrData::Notification(Notification::PeerAddrChange(PeerAddrChange {
+                address,
+                state: PeerAddrState::Added,
+                ..
+            })) => {
+                assert_eq!(address.ip(), added.ip());
+                break;
+            }
+            NotificationOrData::Notification(_) => continue,
+            x => panic!("Should never come here!: {:#?}", x),
+        }
+    }
+}
+
+#[tokio::test]
+async fn connected_recv_into_reuses_buffer() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = accepted.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let mut buf = Vec::with_capacity(8192);
+    let buf_ptr = buf.as_ptr();
+    for payload in [b"hello".to_vec(), b"world!".to_vec()] {
+        let senddata = SendData {
+            payload: payload.clone(),
+            snd_info: None,
+            auth_key_id: None,
+        };
+        let result = connected.sctp_send(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        let result = accepted.recv_into(&mut buf).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let info = result.unwrap();
+        assert!(!info.notification, "{:#?}", info);
+        assert!(info.eor, "{:#?}", info);
+        assert!(info.rcv_info.is_some(), "{:#?}", info);
+        assert_eq!(buf, payload);
+        assert_eq!(buf.as_ptr(), buf_ptr, "Buffer reallocated.");
+    }
+}
+
+#[tokio::test]
+async fn connected_auth_key_state_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    // Needs `net.sctp.auth_enable` to be set.
+    let result = client_socket.set_shared_key(0.into(), 1, b"first secret");
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _client_addr) = accept.unwrap();
+
+    let result = connected.set_shared_key(0.into(), 2, b"second secret");
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.activate_key(0.into(), 2);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.auth_key_state(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(
+        result.unwrap(),
+        AuthKeyState {
+            active: 2,
+            installed: vec![0, 1, 2],
+        }
+    );
+}
+
+#[tokio::test]
+async fn connected_shutdown_state_progress() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _client_addr) = accept.unwrap();
+
+    let result = connected.shutdown_state(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), ConnState::Established);
+
+    let result = connected.shutdown(std::net::Shutdown::Write);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // The peer may complete the shutdown right away on the loopback.
+    let result = connected.shutdown_state(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let state = result.unwrap();
+    assert!(
+        matches!(state, ConnState::ShutdownSent | ConnState::Closed),
+        "{:#?}",
+        state
+    );
+
+    for _ in 0..100 {
+        let result = connected.shutdown_state(assoc_id);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        if result.unwrap() == ConnState::Closed {
+            return;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    panic!("Should never come here!: Shutdown not completed.");
+}
+
+#[tokio::test]
+async fn connected_send_round_robin_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = accepted.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let payloads = vec![
+        b"one".to_vec(),
+        b"two".to_vec(),
+        b"three".to_vec(),
+        b"four".to_vec(),
+    ];
+    let result = connected.send_round_robin(&payloads, &[1, 2]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let mut received_on = std::collections::HashMap::<u16, Vec<Vec<u8>>>::new();
+    for _ in 0..payloads.len() {
+        let result = accepted.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        match result.unwrap() {
+            NotificationOrData::Data(ReceivedData {
+                payload,
+                rcv_info: Some(rcv_info),
+                ..
+            }) => received_on.entry(rcv_info.sid).or_default().push(payload),
+            x => panic!("Should never come here!: {:#?}", x),
+        }
+    }
+    assert_eq!(
+        received_on.get(&1),
+        Some(&vec![b"one".to_vec(), b"three".to_vec()])
+    );
+    assert_eq!(
+        received_on.get(&2),
+        Some(&vec![b"two".to_vec(), b"four".to_vec()])
+    );
+
+    let result = connected.send_round_robin(&payloads, &[]).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[tokio::test]
+async fn connected_send_fitting_multiple_messages() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = connected.fragmentation_point(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let fragmentation_pt = result.unwrap() as usize;
+
+    let payload = vec![1_u8; 2 * fragmentation_pt + 100];
+    let result = connected.send_fitting(&payload, 1).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 3);
+
+    let mut received = vec![];
+    for _ in 0..3 {
+        let result = accepted.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        match result.unwrap() {
+            NotificationOrData::Data(data) => {
+                assert!(data.payload.len() <= fragmentation_pt);
+                received.extend(data.payload);
+            }
+            x => panic!("Should never come here!: {:#?}", x),
+        }
+    }
+    assert_eq!(received, payload);
+}
+
+#[tokio::test]
+async fn connected_ping_loopback() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _client_addr) = accept.unwrap();
+
+    let result = connected
+        .ping(assoc_id, std::time::Duration::from_secs(1))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let rtt = result.unwrap();
+    assert!(rtt < std::time::Duration::from_millis(100), "{:?}", rtt);
+}
+
+#[tokio::test]
+async fn connected_last_send_blocked_send_buffer_full() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    // The accepted socket never receives, so the send buffer eventually fills up.
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (_accepted, _client_addr) = accept.unwrap();
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(!connected.last_send_blocked());
+
+    let mut blocked = false;
+    for _ in 0..1000 {
+        let senddata = SendData {
+            payload: vec![1_u8; 65536],
+            snd_info: None,
+            auth_key_id: None,
+        };
+        match connected.sctp_send(senddata).await {
+            Ok(_) => continue,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                blocked = true;
+                break;
+            }
+            Err(e) => panic!("Should never come here!: {:#?}", e),
+        }
+    }
+    assert!(blocked);
+    assert!(connected.last_send_blocked());
+}
+
+#[tokio::test]
+async fn connected_try_recv_would_block() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = accepted.set_nonblocking(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // The blocking mode would block the runtime.
+    let result = accepted.set_nonblocking(false);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    let result = accepted.try_recv();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap().is_none());
+
+    let senddata = SendData {
+        payload: b"hello".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.sctp_send(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let received = loop {
+        match accepted.try_recv() {
+            Ok(Some(received)) => break received,
+            Ok(None) => tokio::time::sleep(std::time::Duration::from_millis(10)).await,
+            Err(e) => panic!("Should never come here!: {:#?}", e),
+        }
+    };
+    match received {
+        NotificationOrData::Data(ReceivedData { payload, .. }) => {
+            assert_eq!(payload, b"hello".to_vec())
+        }
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+#[tokio::test]
+async fn connected_interleaving_active_both_ends() {
+    let server_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    for socket in [&server_socket, &client_socket] {
+        let result = socket.set_interleaving(true);
+        if let Err(ref e) = result {
+            // Interleaving is disabled by default on Linux (`net.sctp.intl_enable`).
+            assert_eq!(e.kind(), std::io::ErrorKind::PermissionDenied, "{:#?}", e);
+            return;
+        }
+    }
+
+    let port = TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let bindaddr: std::net::SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let result = server_socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let listener = server_socket.listen(10);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = connected.interleaving_active(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let result = accepted.interleaving_active(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn connected_send_tracked_ssns() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let result = accepted.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    for expected in 0..2 {
+        let senddata = SendData {
+            payload: b"hello world!".to_vec(),
+            snd_info: Some(SendInfo {
+                sid: 1,
+                ..Default::default()
+            }),
+            auth_key_id: None,
+        };
+        let result = connected.send_tracked(senddata).await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert_eq!(result.unwrap(), expected);
+
+        let received = accepted.sctp_recv().await;
+        assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+        match received.unwrap() {
+            NotificationOrData::Data(ReceivedData {
+                rcv_info: Some(rcv_info),
+                ..
+            }) => assert_eq!((rcv_info.sid, rcv_info.ssn), (1, expected)),
+            x => panic!("Should never come here!: {:#?}", x),
+        }
+    }
+
+    // Without a `SendInfo` the message is counted on the stream of the default send parameters.
+    let result = connected.set_default_sndinfo(
+        SendInfo {
+            sid: 1,
+            ..Default::default()
+        },
+        0.into(),
+    );
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let senddata = SendData {
+        payload: b"hello world!".to_vec(),
+        snd_info: None,
+        auth_key_id: None,
+    };
+    let result = connected.send_tracked(senddata).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 2);
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        NotificationOrData::Data(ReceivedData {
+            rcv_info: Some(rcv_info),
+            ..
+        }) => assert_eq!((rcv_info.sid, rcv_info.ssn), (1, 2)),
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+#[tokio::test]
+async fn connected_adaptation_indication_received() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = listener.set_adaptation_indication(0x1234);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket
+        .sctp_subscribe_events(&[Event::AdaptationLayer], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let received = connected.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        NotificationOrData::Notification(Notification::AdaptationIndication(adaptation)) => {
+            assert_eq!(adaptation.adaptation_ind, 0x1234);
+            assert_eq!(adaptation.assoc_id, assoc_id);
+        }
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+// A blocking One to One client socket bound to the given port with `SO_REUSEADDR`, so that a
+// second client can use the same port to restart the association.
+fn connect_reuseaddr_client(port: u16, server: std::net::SocketAddr) -> std::io::Result<i32> {
+    let to_sockaddr_in = |ip: std::net::Ipv4Addr, port: u16| libc::sockaddr_in {
+        sin_family: libc::AF_INET as libc::sa_family_t,
+        sin_port: port.to_be(),
+        sin_addr: libc::in_addr {
+            s_addr: u32::from(ip).to_be(),
+        },
+        sin_zero: [0; 8],
+    };
+    let server_ip = match server.ip() {
+        std::net::IpAddr::V4(ip) => ip,
+        std::net::IpAddr::V6(_) => unreachable!(),
+    };
+
+    unsafe {
+        let fd = libc::socket(libc::AF_INET, libc::SOCK_STREAM, libc::IPPROTO_SCTP);
+        if fd < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        let on: libc::c_int = 1;
+        let bindaddr = to_sockaddr_in(std::net::Ipv4Addr::LOCALHOST, port);
+        let connectaddr = to_sockaddr_in(server_ip, server.port());
+        if libc::setsockopt(
+            fd,
+            libc::SOL_SOCKET,
+            libc::SO_REUSEADDR,
+            &on as *const _ as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        ) < 0
+            || libc::bind(
+                fd,
+                &bindaddr as *const _ as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_in>() as libc::socklen_t,
+            ) < 0
+            || libc::connect(
+                fd,
+                &connectaddr as *const _ as *const libc::sockaddr,
+                std::mem::size_of::<libc::sockaddr_in>() as libc::socklen_t,
+            ) < 0
+        {
+            let err = std::io::Error::last_os_error();
+            libc::close(fd);
+            return Err(err);
+        }
+
+        Ok(fd)
+    }
+}
+
+#[tokio::test]
+async fn connected_resubscribe_on_restart_success() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let port = TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let first = connect_reuseaddr_client(port, bindaddr);
+    assert!(first.is_ok(), "{:#?}", first.err().unwrap());
+    let first = ConnectedSocket::from_rawfd(first.unwrap());
+    assert!(first.is_ok(), "{:#?}", first.err().unwrap());
+    let _first = first.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let events = [Event::Association, Event::Shutdown];
+    let result = accepted.sctp_subscribe_events(&events, SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    accepted.set_resubscribe_on_restart(&events);
+
+    // A new association from the same address and port restarts the existing association.
+    let second = connect_reuseaddr_client(port, bindaddr);
+    assert!(second.is_ok(), "{:#?}", second.err().unwrap());
+    let second = ConnectedSocket::from_rawfd(second.unwrap());
+    assert!(second.is_ok(), "{:#?}", second.err().unwrap());
+    let second = second.unwrap();
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+            state: AssocChangeState::Restart,
+            ..
+        })) => {}
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+
+    // The events are still received after the restart.
+    let result = second.shutdown(std::net::Shutdown::Write);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let received = accepted.sctp_recv().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        NotificationOrData::Notification(Notification::Shutdown(_)) => {}
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+#[tokio::test]
+async fn connected_resubscribe_on_restart_notification_queue() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let port = TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let first = connect_reuseaddr_client(port, bindaddr);
+    assert!(first.is_ok(), "{:#?}", first.err().unwrap());
+    let first = ConnectedSocket::from_rawfd(first.unwrap());
+    assert!(first.is_ok(), "{:#?}", first.err().unwrap());
+    let _first = first.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+    let (accepted, _client_addr) = accept.unwrap();
+
+    let events = [Event::Association, Event::Shutdown];
+    let result = accepted.sctp_subscribe_events(&events, SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    accepted.set_resubscribe_on_restart(&events);
+
+    let second = connect_reuseaddr_client(port, bindaddr);
+    assert!(second.is_ok(), "{:#?}", second.err().unwrap());
+    let second = ConnectedSocket::from_rawfd(second.unwrap());
+    assert!(second.is_ok(), "{:#?}", second.err().unwrap());
+    let second = second.unwrap();
+
+    let mut queue = accepted.notification_queue();
+    let received = queue.next_notification().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        Notification::AssociationChange(AssociationChange {
+            state: AssocChangeState::Restart,
+            ..
+        }) => {}
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+
+    let result = second.shutdown(std::net::Shutdown::Write);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let received = queue.next_notification().await;
+    assert!(received.is_ok(), "{:#?}", received.err().unwrap());
+    match received.unwrap() {
+        Notification::Shutdown(_) => {}
+        x => panic!("Should never come here!: {:#?}", x),
+    }
+}
+
+#[tokio::test]
+async fn connected_association_pmtu_multihomed() {
+    let port = TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let bindaddrs: Vec<std::net::SocketAddr> = vec![
+        format!("127.0.0.1:{}", port).parse().unwrap(),
+        format!("127.0.0.2:{}", port).parse().unwrap(),
+    ];
+    let listener = Listener::bind_multi(&bindaddrs, SocketToAssociation::OneToOne);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&bindaddrs).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    // Lower the Path MTU of one of the paths, with the Path MTU discovery disabled
+    // (`SPP_PMTUD_DISABLE`).
+    let params = PeerAddrParams {
+        assoc_id,
+        address: Some(bindaddrs[1]),
+        path_mtu: 1200,
+        flags: 1 << 4,
+        ..Default::default()
+    };
+    let result = connected.set_peer_addr_params(params);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let mut path_mtus = vec![];
+    for addr in &bindaddrs {
+        let result = connected.peer_addr_params(assoc_id, Some(*addr));
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        path_mtus.push(result.unwrap().path_mtu);
+    }
+
+    let result = connected.association_pmtu(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let pmtu = result.unwrap();
+    assert_eq!(pmtu, *path_mtus.iter().min().unwrap(), "{:?}", path_mtus);
+    assert_eq!(pmtu, 1200);
+}
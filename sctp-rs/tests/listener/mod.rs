@@ -17,10 +17,19 @@ async fn listening_one_2_one_listen_accept_success() {
 
     // Get Peer Address
     let (accepted, _address) = accept.unwrap();
-    let result = accepted.sctp_getpaddrs(0);
+    let result = accepted.sctp_getpaddrs(0.into());
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
 }
 
+#[tokio::test]
+async fn listening_one_2_one_idle_try_accept_would_block() {
+    let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let result = listener.try_accept();
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().kind(), std::io::ErrorKind::WouldBlock);
+}
+
 #[tokio::test]
 async fn listening_one_2_many_listen_accept_failure() {
     let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
@@ -52,7 +61,7 @@ async fn listening_sctp_bindx_add_success() {
 async fn listening_socket_no_connect_peeloff_failure() {
     let (listener, _) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
 
-    let result = listener.sctp_peeloff(42);
+    let result = listener.sctp_peeloff(42.into());
     assert!(result.is_err(), "{:#?}", result.ok().unwrap());
 }
 
@@ -69,7 +78,7 @@ async fn listening_socket_one2one_connected_peeloff_failure() {
     let assoc_id = client_socket.sctp_connectx(&[bindaddr]).await;
     assert!(assoc_id.is_ok(), "{:#?}", assoc_id.err().unwrap());
 
-    let received = listener.sctp_peeloff(0);
+    let received = listener.sctp_peeloff(0.into());
     assert!(received.is_err(), "{:#?}", received.ok().unwrap());
 }
 
@@ -111,7 +120,7 @@ async fn listening_socket_one2many_connected_peeloff_success() {
         assert!(received.is_ok(), "{:#?}", received.err().unwrap());
         assert!(state == AssocChangeState::CommUp, "{:#?}", state);
     } else {
-        assert!(false, "Should never come here!: {:#?}", notification);
+        panic!("Should never come here!: {:#?}", notification);
     };
 }
 
@@ -129,3 +138,146 @@ async fn listening_socket_one2many_connected_peeloff_success() {
 
 // Tests for `sctp_subscribe_event`/`sctp_unsubscribe_event` for Listening Socket.
 // TODO:
+
+async fn one2many_connect_and_get_assoc_ids(
+    listener: &Listener,
+    bindaddr: SocketAddr,
+) -> (ConnectedSocket, AssociationId) {
+    let result =
+        listener.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+    let result =
+        client_socket.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::Future);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, _) = result.unwrap();
+
+    // Client's `CommUp`
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // Server's `CommUp`
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    if let NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+        assoc_id,
+        state: AssocChangeState::CommUp,
+        ..
+    })) = notification
+    {
+        (connected, assoc_id)
+    } else {
+        panic!("Should never come here!: {:#?}", notification);
+    }
+}
+
+#[tokio::test]
+async fn listening_one2many_abort_assoc_comm_lost() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+    let (connected, assoc_id) = one2many_connect_and_get_assoc_ids(&listener, bindaddr).await;
+
+    let result = listener.abort(assoc_id, b"going away").await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    assert!(
+        matches!(
+            notification,
+            NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+                state: AssocChangeState::CommLost,
+                ..
+            }))
+        ),
+        "{:#?}",
+        notification
+    );
+}
+
+#[tokio::test]
+async fn listening_one2many_shutdown_assoc_shutdown_complete() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToMany, true);
+    let (connected, assoc_id) = one2many_connect_and_get_assoc_ids(&listener, bindaddr).await;
+
+    let result = listener.shutdown_assoc(assoc_id).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = connected.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let notification = result.unwrap();
+    assert!(
+        matches!(
+            notification,
+            NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+                state: AssocChangeState::ShutdownComplete,
+                ..
+            }))
+        ),
+        "{:#?}",
+        notification
+    );
+}
+
+#[tokio::test]
+async fn listening_bind_multi_success() {
+    let port = crate::TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let bindaddrs: Vec<SocketAddr> = vec![
+        format!("127.0.0.1:{}", port).parse().unwrap(),
+        format!("127.0.0.2:{}", port).parse().unwrap(),
+    ];
+
+    let listener = Listener::bind_multi(&bindaddrs, SocketToAssociation::OneToOne);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let result = listener.sctp_getladdrs(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let mut laddrs = result.unwrap();
+    laddrs.sort();
+    assert_eq!(laddrs, bindaddrs);
+}
+
+#[tokio::test]
+async fn listening_bind_multi_failure() {
+    let result = Listener::bind_multi(&[], SocketToAssociation::OneToOne);
+    assert!(result.is_err());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    let bindaddrs: Vec<SocketAddr> = vec![
+        "127.0.0.1:8080".parse().unwrap(),
+        "127.0.0.2:8081".parse().unwrap(),
+    ];
+    let result = Listener::bind_multi(&bindaddrs, SocketToAssociation::OneToOne);
+    assert!(result.is_err());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[tokio::test]
+async fn listening_accept_filter_rejects_loopback() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    listener.set_accept_filter(|addr: SocketAddr| !addr.ip().is_loopback());
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.sctp_connectx(&[bindaddr]).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result =
+        tokio::time::timeout(std::time::Duration::from_millis(500), listener.accept()).await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+
+    let result = listener.try_accept();
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().kind(), std::io::ErrorKind::WouldBlock);
+}
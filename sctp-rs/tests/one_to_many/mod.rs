@@ -0,0 +1,334 @@
+use sctp_rs::*;
+
+use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+
+use crate::TEST_PORT_NO;
+
+#[test]
+fn style_specific_methods() {
+    // One to Many only APIs.
+    let _peeloff: fn(&OneToManySocket, AssociationId) -> std::io::Result<OneToOneSocket> =
+        OneToManySocket::sctp_peeloff;
+    let _assoc_ids: fn(&OneToManySocket) -> std::io::Result<Vec<AssociationId>> =
+        OneToManySocket::assoc_ids;
+    let _status: fn(&OneToManySocket, AssociationId) -> std::io::Result<ConnStatus> =
+        OneToManySocket::sctp_get_status;
+
+    // One to One APIs never take an Association ID.
+    let _status: fn(&OneToOneSocket) -> std::io::Result<ConnStatus> =
+        OneToOneSocket::sctp_get_status;
+    let _paddrs: fn(&OneToOneSocket) -> std::io::Result<Vec<SocketAddr>> =
+        OneToOneSocket::sctp_getpaddrs;
+}
+
+#[tokio::test]
+async fn one_to_many_assoc_ids_and_peeloff_success() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let server = OneToManySocket::listen_v4(&[bindaddr], 10);
+    assert!(server.is_ok(), "{:#?}", server.err().unwrap());
+    let server = server.unwrap();
+
+    let result = server.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let client = OneToOneSocket::connect(&[bindaddr]).await;
+    assert!(client.is_ok(), "{:#?}", client.err().unwrap());
+    let client = client.unwrap();
+
+    let result = server.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let assoc_id = match result.unwrap() {
+        NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+            assoc_id,
+            state: AssocChangeState::CommUp,
+            ..
+        })) => assoc_id,
+        other => panic!("Should never come here!: {:#?}", other),
+    };
+
+    let result = server.assoc_ids();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), vec![assoc_id]);
+
+    let peeled = server.sctp_peeloff(assoc_id);
+    assert!(peeled.is_ok(), "{:#?}", peeled.err().unwrap());
+    let peeled = peeled.unwrap();
+
+    let result = client
+        .sctp_send(SendData {
+            payload: b"hello".to_vec(),
+            snd_info: None,
+            auth_key_id: None,
+        })
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = peeled.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let data = result.unwrap();
+    if let NotificationOrData::Data(ReceivedData { payload, .. }) = data {
+        assert_eq!(payload, b"hello".to_vec());
+    } else {
+        panic!("Should never come here!: {:#?}", data);
+    }
+}
+
+#[tokio::test]
+async fn one_to_many_worker_pool_dispatch_success() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let server = OneToManySocket::listen_v4(&[bindaddr], 10);
+    assert!(server.is_ok(), "{:#?}", server.err().unwrap());
+
+    // Every worker reports the payload received on its association, the payload received before
+    // the association is peeled off is reported by the pool.
+    let (tx, rx) = std::sync::mpsc::channel();
+    let unhandled_tx = tx.clone();
+    let pool = WorkerPool::new(server.unwrap(), move |assoc: OneToOneSocket| {
+        let tx = tx.clone();
+        async move {
+            if let Ok(NotificationOrData::Data(ReceivedData { payload, .. })) =
+                assoc.sctp_recv().await
+            {
+                let _ = tx.send(payload);
+            }
+        }
+    });
+    tokio::spawn(async move {
+        pool.run(|received| {
+            if let NotificationOrData::Data(ReceivedData { payload, .. }) = received {
+                let _ = unhandled_tx.send(payload);
+            }
+        })
+        .await
+    });
+
+    let mut clients = vec![];
+    for payload in [b"first", b"other"] {
+        let client = OneToOneSocket::connect(&[bindaddr]).await;
+        assert!(client.is_ok(), "{:#?}", client.err().unwrap());
+        let client = client.unwrap();
+
+        let result = client
+            .sctp_send(SendData {
+                payload: payload.to_vec(),
+                snd_info: None,
+                auth_key_id: None,
+            })
+            .await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        clients.push(client);
+    }
+
+    let mut received = vec![];
+    for _ in 0..100 {
+        received.extend(rx.try_iter());
+        if received.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    received.sort();
+    assert_eq!(received, vec![b"first".to_vec(), b"other".to_vec()]);
+}
+
+#[tokio::test]
+async fn one_to_many_all_peer_addrs_success() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let server = OneToManySocket::listen_v4(&[bindaddr], 10);
+    assert!(server.is_ok(), "{:#?}", server.err().unwrap());
+    let server = server.unwrap();
+
+    let result = server.sctp_subscribe_events(&[Event::Association], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let mut clients = vec![];
+    for _ in 0..2 {
+        let client = OneToOneSocket::connect(&[bindaddr]).await;
+        assert!(client.is_ok(), "{:#?}", client.err().unwrap());
+        clients.push(client.unwrap());
+
+        let result = server.sctp_recv().await;
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    }
+
+    let result = server.all_peer_addrs();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let mut peer_addrs = result
+        .unwrap()
+        .into_iter()
+        .map(|(_, addrs)| addrs)
+        .collect::<Vec<_>>();
+    peer_addrs.sort();
+
+    let mut client_addrs = clients
+        .iter()
+        .map(|client| client.sctp_getladdrs().unwrap())
+        .collect::<Vec<_>>();
+    client_addrs.sort();
+
+    assert_eq!(peer_addrs, client_addrs);
+}
+
+#[tokio::test]
+async fn one_to_many_send_new_peer_peer_assoc_id() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let serveraddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let peeraddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let server = OneToManySocket::listen_v4(&[serveraddr], 10);
+    assert!(server.is_ok(), "{:#?}", server.err().unwrap());
+    let server = server.unwrap();
+
+    let peer = OneToManySocket::listen_v4(&[peeraddr], 10);
+    assert!(peer.is_ok(), "{:#?}", peer.err().unwrap());
+    let peer = peer.unwrap();
+
+    // The association with the peer is set up by the send.
+    let result = server
+        .sctp_send(
+            peeraddr,
+            SendData {
+                payload: b"hello".to_vec(),
+                snd_info: None,
+                auth_key_id: None,
+            },
+        )
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = server.peer_assoc_id(peeraddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let assoc_id = result.unwrap();
+
+    let result = peer.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = server.assoc_ids();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), vec![assoc_id]);
+
+    // Subsequent sends use the same association.
+    let result = server
+        .sctp_send(
+            peeraddr,
+            SendData {
+                payload: b"again".to_vec(),
+                snd_info: None,
+                auth_key_id: None,
+            },
+        )
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = server.peer_assoc_id(peeraddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), assoc_id);
+}
+
+#[tokio::test]
+async fn one_to_many_sctp_sendmsg_success() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let serveraddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let peeraddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let server = OneToManySocket::listen_v4(&[serveraddr], 10);
+    assert!(server.is_ok(), "{:#?}", server.err().unwrap());
+    let server = server.unwrap();
+
+    let peer = OneToManySocket::listen_v4(&[peeraddr], 10);
+    assert!(peer.is_ok(), "{:#?}", peer.err().unwrap());
+    let peer = peer.unwrap();
+
+    let result = peer.sctp_request_rcvinfo(true);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = server
+        .sctp_sendmsg(b"hello", peeraddr, 1, 42, SendFlags::NONE, 1000)
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 5);
+
+    let result = peer.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    match result.unwrap() {
+        NotificationOrData::Data(ReceivedData {
+            payload,
+            rcv_info: Some(rcv_info),
+            ..
+        }) => {
+            assert_eq!(payload, b"hello".to_vec());
+            assert_eq!(rcv_info.sid, 1);
+            assert_eq!(rcv_info.ppid, 42);
+        }
+        other => panic!("Should never come here!: {:#?}", other),
+    }
+
+    // An IPv6 destination cannot be used on an IPv4 socket.
+    let v6addr: SocketAddr = format!("[::1]:{}", port).parse().unwrap();
+    let result = server
+        .sctp_sendmsg(b"hello", v6addr, 0, 0, SendFlags::NONE, 0)
+        .await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert!(matches!(
+        SctpError::from_io_error(&result.unwrap_err()),
+        Some(SctpError::AddressFamilyMismatch { .. })
+    ));
+}
+
+#[tokio::test]
+async fn one_to_many_send_failed_carries_context() {
+    let port = TEST_PORT_NO.fetch_add(1, Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+
+    let socket = OneToManySocket::listen_v4(&[bindaddr], 10);
+    assert!(socket.is_ok(), "{:#?}", socket.err().unwrap());
+    let socket = socket.unwrap();
+
+    // Give up on setting up the association quickly.
+    let result = socket.sctp_setup_init_params(10, 10, 1, 100);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result =
+        socket.sctp_subscribe_events(&[Event::SendFailureEvent], SubscribeEventAssocId::All);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    // An address from TEST-NET-1 (RFC 5737), that never responds.
+    let unreachable: SocketAddr = "192.0.2.1:8080".parse().unwrap();
+    let result = socket
+        .sctp_send(
+            unreachable,
+            SendData {
+                payload: b"hello".to_vec(),
+                snd_info: Some(SendInfo {
+                    sid: 1,
+                    context: 0xC0FFEE,
+                    ..Default::default()
+                }),
+                auth_key_id: None,
+            },
+        )
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = tokio::time::timeout(std::time::Duration::from_secs(5), socket.sctp_recv()).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let result = result.unwrap();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    match result.unwrap() {
+        NotificationOrData::Notification(Notification::SendFailed(send_failed)) => {
+            assert_eq!(send_failed.context(), 0xC0FFEE);
+            assert_eq!(send_failed.info.sid, 1);
+            assert_eq!(send_failed.data, b"hello".to_vec());
+        }
+        other => panic!("Should never come here!: {:#?}", other),
+    }
+}
@@ -36,6 +36,7 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: None,
+        auth_key_id: None,
     };
     let result = listener.sctp_send(client_addr, senddata.clone()).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -69,7 +70,7 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
         );
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 
     // Now turn off Request Receive Info on client socket
@@ -103,7 +104,7 @@ async fn socket_connect_basic_send_recv_req_info_on_and_off() {
         assert!(rcv_info.is_none(), "{:#?}", rcv_info.unwrap());
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 
@@ -136,6 +137,7 @@ async fn socket_send_recv_nxtinfo_test() {
     let senddata = SendData {
         payload: b"hello world!".to_vec(),
         snd_info: None,
+        auth_key_id: None,
     };
     let result = listener.sctp_send(client_addr, senddata.clone()).await;
     assert!(result.is_ok(), "{:#?}", result.err().unwrap());
@@ -168,7 +170,7 @@ async fn socket_send_recv_nxtinfo_test() {
         assert!(rcv_info.is_none(), "{:#?}", rcv_info.unwrap());
         assert!(nxt_info.is_some());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 
     // First Receive nxtinfo should not be none.
@@ -195,7 +197,7 @@ async fn socket_send_recv_nxtinfo_test() {
         assert!(rcv_info.is_none(), "{:#?}", rcv_info.unwrap());
         assert!(nxt_info.is_none(), "{:#?}", nxt_info.unwrap());
     } else {
-        assert!(false, "Should never come here!: {:#?}", data);
+        panic!("Should never come here!: {:#?}", data);
     };
 }
 
@@ -250,7 +252,7 @@ async fn socket_init_params_set_ostreams_success() {
             ob_streams
         );
     } else {
-        assert!(false, "Should never come here!: {:#?}", notification);
+        panic!("Should never come here!: {:#?}", notification);
     };
 }
 
@@ -322,3 +324,366 @@ async fn test_connect_no_listen_failure() {
     let err = result.err().unwrap();
     assert_eq!(err.raw_os_error(), Some(libc::ECONNREFUSED));
 }
+
+#[tokio::test]
+async fn test_set_get_rto_info_defaults_success() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let rto_info = RtoInfo {
+        assoc_id: 0.into(),
+        initial: 1000,
+        max: 5000,
+        min: 500,
+    };
+    let result = sctp_socket.set_rto_info(rto_info);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.get_rto_info(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), rto_info);
+}
+
+#[tokio::test]
+async fn test_set_rto_info_min_greater_than_max_failure() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let rto_info = RtoInfo {
+        assoc_id: 0.into(),
+        initial: 1000,
+        max: 500,
+        min: 5000,
+    };
+    let result = sctp_socket.set_rto_info(rto_info);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_set_udp_encaps_port_defaults_success() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let any: SocketAddr = "0.0.0.0:0".parse().unwrap();
+
+    let result = sctp_socket.set_udp_encaps_port(0.into(), any, 9899);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.set_udp_encaps_port(0.into(), any, 0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn test_set_get_assoc_info_defaults_success() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let result = sctp_socket.set_assoc_info(AssocInfo {
+        assoc_id: 0.into(),
+        max_rxt: 7,
+        cookie_life: 30000,
+        // Read-only, ignored while setting.
+        peer_rwnd: 1234,
+        ..Default::default()
+    });
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.get_assoc_info(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let assoc_info = result.unwrap();
+    assert_eq!(assoc_info.max_rxt, 7, "{:#?}", assoc_info);
+    assert_eq!(assoc_info.cookie_life, 30000, "{:#?}", assoc_info);
+    assert_ne!(assoc_info.peer_rwnd, 1234, "{:#?}", assoc_info);
+}
+
+#[tokio::test]
+async fn test_builder_subscribe_first_comm_up_success() {
+    let sctp_socket = SocketBuilder::new(SocketToAssociation::OneToMany)
+        .subscribe(&[Event::Association])
+        .build();
+    assert!(sctp_socket.is_ok(), "{:#?}", sctp_socket.err().unwrap());
+    let sctp_socket = sctp_socket.unwrap();
+
+    let port = super::TEST_PORT_NO.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let bindaddr: SocketAddr = format!("127.0.0.1:{}", port).parse().unwrap();
+    let result = sctp_socket.bind(bindaddr);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let listener = sctp_socket.listen(10);
+    assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+    let listener = listener.unwrap();
+
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let result = client_socket.connect(bindaddr).await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = listener.sctp_recv().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let received = result.unwrap();
+    assert!(
+        matches!(
+            received,
+            NotificationOrData::Notification(Notification::AssociationChange(AssociationChange {
+                state: AssocChangeState::CommUp,
+                ..
+            }))
+        ),
+        "{:#?}",
+        received
+    );
+}
+
+#[tokio::test]
+async fn test_set_get_maxseg_defaults_success() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let result = sctp_socket.set_maxseg(0.into(), 1000);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.maxseg(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 1000);
+
+    // `0` restores the Path MTU based fragment size.
+    let result = sctp_socket.set_maxseg(0.into(), 0);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn test_set_get_autoclose_success() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToMany, true);
+
+    let result = sctp_socket.set_autoclose(30);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.autoclose();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 30);
+}
+
+#[tokio::test]
+async fn test_set_autoclose_one_to_one_failure() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = sctp_socket.set_autoclose(30);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::OneToManyOnly)
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[tokio::test]
+async fn test_set_get_priority_success() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = sctp_socket.set_priority(4);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = sctp_socket.priority();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), 4);
+}
+
+#[tokio::test]
+async fn test_set_get_buffer_sizes_success() {
+    for assoc in [
+        SocketToAssociation::OneToOne,
+        SocketToAssociation::OneToMany,
+    ] {
+        let sctp_socket = create_client_socket(assoc, true);
+
+        let result = sctp_socket.set_recv_buffer_size(65536);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let result = sctp_socket.recv_buffer_size();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        #[cfg(target_os = "linux")]
+        assert_eq!(result.unwrap(), 2 * 65536);
+
+        let result = sctp_socket.set_send_buffer_size(65536);
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let result = sctp_socket.send_buffer_size();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        #[cfg(target_os = "linux")]
+        assert_eq!(result.unwrap(), 2 * 65536);
+    }
+}
+
+#[tokio::test]
+async fn socket_bind_v6_on_v4_address_family_mismatch() {
+    let sctp_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let v6addr: SocketAddr = "[::1]:8080".parse().unwrap();
+    let v4addr: SocketAddr = "127.0.0.1:8080".parse().unwrap();
+    let result = sctp_socket.sctp_bindx(&[v4addr, v6addr], BindxFlags::Add);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    let err = result.err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::AddressFamilyMismatch {
+            family: libc::AF_INET,
+            addrs: vec![v6addr],
+        }),
+        "{:#?}",
+        err
+    );
+}
+
+#[tokio::test]
+async fn socket_connect_timeout_established() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    let result = client_socket
+        .connect_timeout(bindaddr, std::time::Duration::from_secs(1))
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (connected, assoc_id) = result.unwrap();
+
+    let accept = listener.accept().await;
+    assert!(accept.is_ok(), "{:#?}", accept.err().unwrap());
+
+    let result = connected.sctp_get_status(assoc_id);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
+
+#[tokio::test]
+async fn socket_connect_timeout_timed_out() {
+    let client_socket = create_client_socket(SocketToAssociation::OneToOne, true);
+
+    // An address from TEST-NET-1 (RFC 5737), that never responds.
+    let unreachable: SocketAddr = "192.0.2.1:8080".parse().unwrap();
+    let result = client_socket
+        .connect_timeout(unreachable, std::time::Duration::from_millis(100))
+        .await;
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(result.err().unwrap().kind(), std::io::ErrorKind::TimedOut);
+}
+
+#[tokio::test]
+async fn sctp_defaults_read_success() {
+    let result = SctpDefaults::read();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let defaults = result.unwrap();
+
+    let rto_info = &defaults.rto_info;
+    assert!(rto_info.min > 0, "{:#?}", defaults);
+    assert!(rto_info.min <= rto_info.initial, "{:#?}", defaults);
+    assert!(rto_info.initial <= rto_info.max, "{:#?}", defaults);
+    assert!(defaults.assoc_info.max_rxt > 0, "{:#?}", defaults);
+    assert!(defaults.assoc_info.cookie_life > 0, "{:#?}", defaults);
+    assert!(defaults.ostreams > 0, "{:#?}", defaults);
+    assert!(defaults.istreams > 0, "{:#?}", defaults);
+    assert!(defaults.init_retries > 0, "{:#?}", defaults);
+}
+
+#[tokio::test]
+async fn socket_handshake_params_success() {
+    let result = SocketBuilder::new(SocketToAssociation::OneToOne)
+        .handshake(
+            HandshakeParams::new()
+                .cookie_life(10_000)
+                .max_init_attempts(4)
+                .max_init_timeout(5_000),
+        )
+        .build();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let sctp_socket = result.unwrap();
+
+    let result = sctp_socket.get_assoc_info(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().cookie_life, 10_000);
+}
+
+#[tokio::test]
+async fn socket_local_addr_ephemeral_port() {
+    for v4 in [true, false] {
+        let socket = create_client_socket(SocketToAssociation::OneToOne, v4);
+        let ip = if v4 {
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        } else {
+            IpAddr::V6(Ipv6Addr::LOCALHOST)
+        };
+
+        let result = socket.bind(SocketAddr::new(ip, 0));
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+        let result = socket.local_addr();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        let local_addr = result.unwrap();
+        assert_eq!(local_addr.ip(), ip);
+        assert_ne!(local_addr.port(), 0);
+
+        let listener = socket.listen(10);
+        assert!(listener.is_ok(), "{:#?}", listener.err().unwrap());
+        let result = listener.unwrap().local_addr();
+        assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+        assert_eq!(result.unwrap(), local_addr);
+    }
+}
+
+#[tokio::test]
+async fn socket_configure_bounded_latency_success() {
+    let socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    let max_latency = std::time::Duration::from_millis(250);
+
+    let result = socket.configure_bounded_latency(max_latency);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+
+    let result = socket.default_pr_ttl(0.into());
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap(), Some(max_latency));
+
+    let result = socket.nodelay();
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert!(result.unwrap());
+
+    let result = socket.configure_bounded_latency(std::time::Duration::from_micros(10));
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+}
+
+#[tokio::test]
+async fn socket_is_sctp_and_from_raw_fd_checked() {
+    use std::os::unix::io::IntoRawFd;
+
+    let socket = create_client_socket(SocketToAssociation::OneToOne, true);
+    assert!(socket.is_sctp());
+
+    let tcp_listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let rawfd = tcp_listener.into_raw_fd();
+    let result = ConnectedSocket::from_raw_fd_checked(rawfd);
+    assert!(result.is_err(), "{:#?}", result.ok().unwrap());
+    assert_eq!(
+        result.err().unwrap().kind(),
+        std::io::ErrorKind::InvalidInput
+    );
+
+    // The rejected socket is still owned by the caller.
+    // Safety: `rawfd` is a valid open socket, not owned by anything else.
+    unsafe {
+        assert_eq!(libc::close(rawfd), 0);
+    }
+}
+
+#[tokio::test]
+async fn client_builder_connect_comm_up() {
+    let (listener, bindaddr) = create_socket_bind_and_listen(SocketToAssociation::OneToOne, true);
+
+    let result = ClientBuilder::new()
+        .init_msg(5, 5, 0, 0)
+        .subscribe(&[Event::Shutdown])
+        .wait_established(std::time::Duration::from_secs(1))
+        .connect(&[bindaddr])
+        .await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    let (_connected, comm_up) = result.unwrap();
+    assert_eq!(comm_up.state, AssocChangeState::CommUp);
+    assert_eq!(comm_up.ob_streams, 5);
+    assert_eq!(comm_up.ib_streams, 5);
+
+    let result = listener.accept().await;
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+}
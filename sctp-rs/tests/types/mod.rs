@@ -0,0 +1,543 @@
+use sctp_rs::*;
+
+#[test]
+fn notification_assoc_id() {
+    let assoc_change = Notification::AssociationChange(AssociationChange {
+        ev_type: Event::Association,
+        flags: 0,
+        length: 20,
+        state: AssocChangeState::CommUp,
+        error: 0,
+        ob_streams: 10,
+        ib_streams: 10,
+        assoc_id: 3.into(),
+        info: vec![],
+    });
+    assert_eq!(assoc_change.assoc_id(), Some(3.into()));
+
+    let shutdown = Notification::Shutdown(Shutdown {
+        ev_type: Event::Shutdown,
+        flags: 0,
+        length: 12,
+        assoc_id: 5.into(),
+    });
+    assert_eq!(shutdown.assoc_id(), Some(5.into()));
+
+    assert_eq!(Notification::Unsupported.assoc_id(), None);
+}
+
+#[test]
+fn association_change_features() {
+    let mut assoc_change = AssociationChange {
+        ev_type: Event::Association,
+        flags: 0,
+        length: 20,
+        state: AssocChangeState::CommUp,
+        error: 0,
+        ob_streams: 10,
+        ib_streams: 10,
+        assoc_id: 3.into(),
+        info: vec![],
+    };
+    assert_eq!(assoc_change.features(), AssocFeatures::default());
+
+    assoc_change.info = vec![0x01, 0x03];
+    let features = assoc_change.features();
+    assert_eq!(features.pr, Some(true), "{:#?}", features);
+    assert_eq!(features.auth, Some(false), "{:#?}", features);
+    assert_eq!(features.asconf, Some(true), "{:#?}", features);
+
+    // For the `CommLost`, `info` contains the ABORT chunk and not the features.
+    assoc_change.state = AssocChangeState::CommLost;
+    assert_eq!(assoc_change.features(), AssocFeatures::default());
+}
+
+#[test]
+fn notification_event_type() {
+    let assoc_change = Notification::AssociationChange(AssociationChange {
+        ev_type: Event::Association,
+        flags: 0,
+        length: 20,
+        state: AssocChangeState::CommUp,
+        error: 0,
+        ob_streams: 10,
+        ib_streams: 10,
+        assoc_id: 3.into(),
+        info: vec![],
+    });
+    assert_eq!(assoc_change.event_type(), Event::Association);
+    assert_eq!(Notification::Unsupported.event_type(), Event::Unknown);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn notification_serde_roundtrip() {
+    let notification = Notification::AssociationChange(AssociationChange {
+        ev_type: Event::Association,
+        flags: 0,
+        length: 20,
+        state: AssocChangeState::CommUp,
+        error: 0,
+        ob_streams: 10,
+        ib_streams: 10,
+        assoc_id: 3.into(),
+        info: vec![],
+    });
+
+    let json = serde_json::to_string(&notification);
+    assert!(json.is_ok(), "{:#?}", json.err().unwrap());
+    let json = json.unwrap();
+    assert!(json.contains("CommUp"), "{}", json);
+
+    let decoded: Result<Notification, _> = serde_json::from_str(&json);
+    assert!(decoded.is_ok(), "{:#?}", decoded.err().unwrap());
+    assert_eq!(decoded.unwrap(), notification);
+}
+
+#[test]
+fn sctp_error_from_io_error() {
+    let err: std::io::Error = SctpError::InitMsgAfterConnect.into();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::InitMsgAfterConnect)
+    );
+
+    let err = std::io::Error::from_raw_os_error(libc::EINVAL);
+    assert_eq!(SctpError::from_io_error(&err), None);
+}
+
+#[test]
+fn notification_from_truncated_bytes() {
+    // `SCTP_ASSOC_CHANGE` header with `COMM_UP` state and two bytes of `info`.
+    let mut assoc_change = vec![];
+    assoc_change.extend(0x8001_u16.to_ne_bytes());
+    assoc_change.extend(0_u16.to_ne_bytes());
+    assoc_change.extend(22_u32.to_ne_bytes());
+    assoc_change.extend(0_u16.to_ne_bytes());
+    assoc_change.extend(0_u16.to_ne_bytes());
+    assoc_change.extend(10_u16.to_ne_bytes());
+    assoc_change.extend(10_u16.to_ne_bytes());
+    assoc_change.extend(3_i32.to_ne_bytes());
+    assoc_change.extend([1_u8, 2]);
+
+    let notification = Notification::from_bytes(&assoc_change);
+    assert!(
+        matches!(
+            notification,
+            Notification::AssociationChange(AssociationChange { assoc_id, ref info, .. }) if assoc_id == 3.into() && info == &[1, 2]
+        ),
+        "{:#?}",
+        notification
+    );
+
+    // Trailing bytes beyond the reported `length` are not part of `info`.
+    let mut padded = assoc_change.clone();
+    padded.extend([0xff_u8; 16]);
+    let notification = Notification::from_bytes(&padded);
+    assert!(
+        matches!(
+            notification,
+            Notification::AssociationChange(AssociationChange { ref info, .. }) if info == &[1, 2]
+        ),
+        "{:#?}",
+        notification
+    );
+
+    // Every truncation of the buffer (including a shorter reported `length`) is parsed without a
+    // panic and the truncated structures are `Unsupported`.
+    for len in 0..20 {
+        let notification = Notification::from_bytes(&assoc_change[..len]);
+        assert_eq!(notification, Notification::Unsupported, "len: {}", len);
+
+        let mut short_length = assoc_change.clone();
+        short_length[4..8].copy_from_slice(&(len as u32).to_ne_bytes());
+        let notification = Notification::from_bytes(&short_length);
+        assert_eq!(notification, Notification::Unsupported, "length: {}", len);
+    }
+
+    // `SCTP_PEER_ADDR_CHANGE` and `SCTP_SHUTDOWN_EVENT` with only the header.
+    for ev_type in [0x8002_u16, 0x8005] {
+        let mut header = vec![];
+        header.extend(ev_type.to_ne_bytes());
+        header.extend(0_u16.to_ne_bytes());
+        header.extend(148_u32.to_ne_bytes());
+        let notification = Notification::from_bytes(&header);
+        assert_eq!(notification, Notification::Unsupported, "{:x}", ev_type);
+    }
+}
+
+#[test]
+fn stream_ssn_tracker_gap() {
+    let mut tracker = StreamSsnTracker::new();
+
+    let gaps = [0, 1, 3]
+        .iter()
+        .filter_map(|ssn| {
+            tracker.track(&RcvInfo {
+                sid: 2,
+                ssn: *ssn,
+                assoc_id: 1.into(),
+                ..Default::default()
+            })
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        gaps,
+        vec![SsnGap {
+            assoc_id: 1.into(),
+            sid: 2,
+            expected: 2,
+            received: 3
+        }]
+    );
+    assert_eq!(gaps[0].missing(), 1);
+
+    // Streams of other associations are tracked separately.
+    let gap = tracker.track(&RcvInfo {
+        sid: 2,
+        ssn: 0,
+        assoc_id: 2.into(),
+        ..Default::default()
+    });
+    assert!(gap.is_none(), "{:#?}", gap);
+
+    // SSNs start again from `0` after a reset.
+    tracker.reset(1.into());
+    let gap = tracker.track(&RcvInfo {
+        sid: 2,
+        ssn: 0,
+        assoc_id: 1.into(),
+        ..Default::default()
+    });
+    assert!(gap.is_none(), "{:#?}", gap);
+}
+
+#[test]
+fn sctp_error_socket_option_context() {
+    let err: std::io::Error = SctpError::SocketOption {
+        option: "SCTP_INITMSG",
+        errno: libc::EINVAL,
+    }
+    .into();
+
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    let message = err.to_string();
+    assert!(message.contains("SCTP_INITMSG"), "{}", message);
+    assert_eq!(
+        SctpError::from_io_error(&err).and_then(SctpError::raw_os_error),
+        Some(libc::EINVAL)
+    );
+}
+
+#[test]
+fn sctp_error_socket_create_context() {
+    let err: std::io::Error = SctpError::SocketCreate {
+        family: libc::AF_INET6,
+        style: SocketToAssociation::OneToMany,
+        errno: libc::EPROTONOSUPPORT,
+    }
+    .into();
+
+    assert_eq!(
+        err.kind(),
+        std::io::Error::from_raw_os_error(libc::EPROTONOSUPPORT).kind()
+    );
+    let message = err.to_string();
+    assert!(message.contains("AF_INET6"), "{}", message);
+    assert!(message.contains("OneToMany"), "{}", message);
+
+    match SctpError::from_io_error(&err) {
+        Some(SctpError::SocketCreate {
+            family,
+            style,
+            errno,
+        }) => {
+            assert_eq!(*family, libc::AF_INET6);
+            assert_eq!(*style, SocketToAssociation::OneToMany);
+            assert_eq!(*errno, libc::EPROTONOSUPPORT);
+        }
+        other => panic!("Should never come here!: {:#?}", other),
+    }
+    assert_eq!(
+        SctpError::from_io_error(&err).and_then(SctpError::raw_os_error),
+        Some(libc::EPROTONOSUPPORT)
+    );
+}
+
+#[test]
+fn association_id_validity() {
+    assert!(AssociationId::new(-1).is_none());
+
+    let assoc_id = AssociationId::new(5);
+    assert!(assoc_id.is_some());
+    let assoc_id = assoc_id.unwrap();
+    assert!(assoc_id.is_valid());
+    assert!(!assoc_id.is_sentinel());
+    assert_eq!(i32::from(assoc_id), 5);
+
+    let future = AssociationId::from(0);
+    assert!(future.is_sentinel());
+    assert!(!future.is_valid());
+    assert_eq!(AssociationId::from(SubscribeEventAssocId::All), 2.into());
+}
+
+#[test]
+fn event_u16_round_trip() {
+    let events = [
+        Event::DataIo,
+        Event::Association,
+        Event::Address,
+        Event::SendFailure,
+        Event::PeerError,
+        Event::Shutdown,
+        Event::PartialDelivery,
+        Event::AdaptationLayer,
+        Event::Authentication,
+        Event::SenderDry,
+        Event::StreamReset,
+        Event::AssociationReset,
+        Event::StreamChange,
+        Event::SendFailureEvent,
+        Event::Unknown,
+    ];
+    for event in events {
+        assert_eq!(Event::from_u16(event.as_u16()), event);
+    }
+
+    assert_eq!(Event::DataIo.as_u16(), 0x8000);
+    assert_eq!(Event::SendFailureEvent.as_u16(), 0x800D);
+}
+
+#[test]
+fn notification_display() {
+    let assoc_change = Notification::AssociationChange(AssociationChange {
+        ev_type: Event::Association,
+        flags: 0,
+        length: 20,
+        state: AssocChangeState::CommUp,
+        error: 0,
+        ob_streams: 10,
+        ib_streams: 10,
+        assoc_id: 3.into(),
+        info: vec![1, 2, 3],
+    });
+    assert_eq!(
+        assoc_change.to_string(),
+        "AssocChange[assoc=3 state=CommUp in=10 out=10]"
+    );
+
+    let peer_addr_change = Notification::PeerAddrChange(PeerAddrChange {
+        ev_type: Event::Address,
+        flags: 0,
+        length: 148,
+        address: "192.0.2.1:9000".parse().unwrap(),
+        state: PeerAddrState::Unreachable,
+        error: 0,
+        assoc_id: 3.into(),
+    });
+    assert_eq!(
+        peer_addr_change.to_string(),
+        "PeerAddrChange[assoc=3 addr=192.0.2.1:9000 state=Unreachable]"
+    );
+
+    assert_eq!(Notification::SenderDryEvent.to_string(), "SenderDry");
+}
+
+#[test]
+fn adaptation_indication_from_bytes() {
+    let mut adaptation = vec![];
+    adaptation.extend(0x8007_u16.to_ne_bytes());
+    adaptation.extend(0_u16.to_ne_bytes());
+    adaptation.extend(16_u32.to_ne_bytes());
+    adaptation.extend(0x1234_u32.to_ne_bytes());
+    adaptation.extend(3_i32.to_ne_bytes());
+
+    let notification = Notification::from_bytes(&adaptation);
+    assert!(
+        matches!(
+            notification,
+            Notification::AdaptationIndication(AdaptationEvent { adaptation_ind: 0x1234, assoc_id, .. }) if assoc_id == 3.into()
+        ),
+        "{:#?}",
+        notification
+    );
+    assert_eq!(notification.assoc_id(), Some(3.into()));
+    assert_eq!(notification.to_string(), "Adaptation[assoc=3 ind=0x1234]");
+
+    let notification = Notification::from_bytes(&adaptation[..12]);
+    assert_eq!(notification, Notification::Unsupported);
+}
+
+#[test]
+fn send_failed_from_bytes_context() {
+    // `SCTP_SEND_FAILED_EVENT` with a `sctp_sndinfo` and the payload.
+    let mut send_failed_event = vec![];
+    send_failed_event.extend(0x800D_u16.to_ne_bytes());
+    send_failed_event.extend(0_u16.to_ne_bytes());
+    send_failed_event.extend(37_u32.to_ne_bytes());
+    send_failed_event.extend(0_u32.to_ne_bytes());
+    send_failed_event.extend(1_u16.to_ne_bytes());
+    send_failed_event.extend(0_u16.to_ne_bytes());
+    send_failed_event.extend(42_u32.to_ne_bytes());
+    send_failed_event.extend(0xC0FFEE_u32.to_ne_bytes());
+    send_failed_event.extend(3_i32.to_ne_bytes());
+    send_failed_event.extend(3_i32.to_ne_bytes());
+    send_failed_event.extend(b"hello");
+
+    // Deprecated `SCTP_SEND_FAILED` with a `sctp_sndrcvinfo` and the payload.
+    let mut send_failed = vec![];
+    send_failed.extend(0x8003_u16.to_ne_bytes());
+    send_failed.extend(0_u16.to_ne_bytes());
+    send_failed.extend(53_u32.to_ne_bytes());
+    send_failed.extend(0_u32.to_ne_bytes());
+    send_failed.extend(1_u16.to_ne_bytes());
+    send_failed.extend([0_u8; 6]);
+    send_failed.extend(42_u32.to_ne_bytes());
+    send_failed.extend(0xC0FFEE_u32.to_ne_bytes());
+    send_failed.extend([0_u8; 12]);
+    send_failed.extend(3_i32.to_ne_bytes());
+    send_failed.extend(3_i32.to_ne_bytes());
+    send_failed.extend(b"hello");
+
+    for (bytes, ev_type) in [
+        (send_failed_event, Event::SendFailureEvent),
+        (send_failed, Event::SendFailure),
+    ] {
+        let notification = Notification::from_bytes(&bytes);
+        assert_eq!(notification.event_type(), ev_type, "{:#?}", notification);
+        assert_eq!(notification.assoc_id(), Some(3.into()));
+        match notification {
+            Notification::SendFailed(send_failed) => {
+                assert_eq!(send_failed.context(), 0xC0FFEE);
+                assert_eq!((send_failed.info.sid, send_failed.info.ppid), (1, 42));
+                assert_eq!(send_failed.data, b"hello".to_vec());
+            }
+            other => panic!("Should never come here!: {:#?}", other),
+        }
+    }
+}
+
+fn pdapi_aborted_bytes(assoc_id: i32, stream: u32) -> Vec<u8> {
+    let mut pdapi = vec![];
+    pdapi.extend(0x8006_u16.to_ne_bytes());
+    pdapi.extend(0_u16.to_ne_bytes());
+    pdapi.extend(24_u32.to_ne_bytes());
+    pdapi.extend(0_u32.to_ne_bytes());
+    pdapi.extend(assoc_id.to_ne_bytes());
+    pdapi.extend(stream.to_ne_bytes());
+    pdapi.extend(7_u32.to_ne_bytes());
+    pdapi
+}
+
+#[test]
+fn pdapi_event_from_bytes() {
+    let notification = Notification::from_bytes(&pdapi_aborted_bytes(3, 1));
+    assert!(
+        matches!(
+            notification,
+            Notification::PartialDeliveryEvent(ref pdapi) if pdapi.is_aborted() && pdapi.assoc_id == 3.into() && pdapi.stream == 1 && pdapi.seq == 7
+        ),
+        "{:#?}",
+        notification
+    );
+    assert_eq!(notification.assoc_id(), Some(3.into()));
+    assert_eq!(notification.event_type(), Event::PartialDelivery);
+    assert_eq!(
+        notification.to_string(),
+        "PartialDelivery[assoc=3 stream=1 ssn=7 aborted]"
+    );
+
+    let notification = Notification::from_bytes(&pdapi_aborted_bytes(3, 1)[..20]);
+    assert_eq!(notification, Notification::Unsupported);
+}
+
+#[test]
+fn message_reassembler_pdapi_abort_discards_parts() {
+    let part = |payload: &[u8]| ReceivedData {
+        payload: payload.to_vec(),
+        rcv_info: Some(RcvInfo {
+            sid: 1,
+            assoc_id: 3.into(),
+            ..Default::default()
+        }),
+        nxt_info: None,
+    };
+    let mut reassembler = MessageReassembler::new();
+
+    assert_eq!(reassembler.push(part(b"aborted "), false), Ok(None));
+    assert_eq!(reassembler.pending(3.into(), 1), 8);
+
+    reassembler.notification(&Notification::from_bytes(&pdapi_aborted_bytes(3, 1)));
+    assert_eq!(reassembler.pending(3.into(), 1), 0);
+
+    assert_eq!(reassembler.push(part(b"hello "), false), Ok(None));
+    let message = reassembler.push(part(b"world"), true);
+    assert!(
+        matches!(message, Ok(Some(ref message)) if message.payload == b"hello world"),
+        "{:#?}",
+        message
+    );
+    assert_eq!(reassembler.pending(3.into(), 1), 0);
+
+    assert_eq!(reassembler.push(part(b"abandoned"), false), Ok(None));
+    reassembler.reset(3.into(), 1);
+    assert_eq!(reassembler.pending(3.into(), 1), 0);
+}
+
+#[test]
+fn message_reassembler_max_message_size() {
+    let part = |payload: &[u8]| ReceivedData {
+        payload: payload.to_vec(),
+        rcv_info: None,
+        nxt_info: None,
+    };
+    let mut reassembler = MessageReassembler::new();
+    reassembler.set_max_message_size(8);
+
+    assert_eq!(reassembler.push(part(b"hello "), false), Ok(None));
+    assert_eq!(reassembler.push(part(b"world"), false), Ok(None));
+    assert_eq!(
+        reassembler.push(part(b"!"), true),
+        Err(SctpError::MessageTooLarge { size: 12, max: 8 })
+    );
+
+    // The oversized message is discarded entirely, the next message is reassembled as is.
+    assert_eq!(reassembler.push(part(b"hi "), false), Ok(None));
+    let message = reassembler.push(part(b"there"), true);
+    assert!(
+        matches!(message, Ok(Some(ref message)) if message.payload == b"hi there"),
+        "{:#?}",
+        message
+    );
+}
+
+#[test]
+fn send_info_for_stream_invalid_stream_id() {
+    let result = SendInfo::for_stream(70000);
+    assert_eq!(result, Err(SctpError::InvalidStreamId { sid: 70000 }));
+
+    let err: std::io::Error = result.err().unwrap().into();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert_eq!(
+        SctpError::from_io_error(&err),
+        Some(&SctpError::InvalidStreamId { sid: 70000 })
+    );
+
+    let result = SendInfo::for_stream(65535);
+    assert!(result.is_ok(), "{:#?}", result.err().unwrap());
+    assert_eq!(result.unwrap().sid, 65535);
+}
+
+#[test]
+fn send_flags_combine() {
+    let flags = SendFlags::UNORDERED | SendFlags::SACK_IMMEDIATELY;
+    assert!(flags.contains(SendFlags::UNORDERED));
+    assert!(flags.contains(SendFlags::SACK_IMMEDIATELY));
+    assert!(!flags.contains(SendFlags::EOF));
+    assert_eq!(flags.bits(), 0x0009);
+
+    let mut flags = SendFlags::NONE;
+    flags |= SendFlags::EOF;
+    assert_eq!(u16::from(flags), libc::MSG_FIN as u16);
+}